@@ -0,0 +1,37 @@
+//! Measures `str_width` on mostly ASCII input like source code and logs.
+//! Run with `cargo bench`.
+
+// benchmarks are only run on recent toolchains and are not bound by the MSRV
+#![allow(clippy::incompatible_msrv)]
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use grapheme_width::{str_width, UnicodeCompat};
+
+const ITERATIONS: u32 = 200;
+
+fn bench(name: &str, input: &str, unicode_compat: UnicodeCompat) {
+    // warmup
+    for _ in 0..ITERATIONS / 10 {
+        black_box(str_width(black_box(input), unicode_compat));
+    }
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(str_width(black_box(input), unicode_compat));
+    }
+    let elapsed = start.elapsed() / ITERATIONS;
+    let throughput = input.len() as f64 / elapsed.as_secs_f64() / (1024.0 * 1024.0);
+    println!("{name:<28} {elapsed:>12?}/iter {throughput:>10.1} MiB/s");
+}
+
+fn main() {
+    let log = "2023-03-01T12:00:00Z INFO server: request handled in 12ms\t(status=200)\n";
+    let log = log.repeat(10_000);
+    bench("ascii log (Unicode9)", &log, UnicodeCompat::Unicode9);
+    bench("ascii log (Unicode14)", &log, UnicodeCompat::Unicode14);
+    // a single non-ASCII character at the end of a long ASCII line
+    let line = format!("{}é", "x".repeat(500_000));
+    bench("ascii prefix (Unicode9)", &line, UnicodeCompat::Unicode9);
+    bench("ascii prefix (Unicode14)", &line, UnicodeCompat::Unicode14);
+}
@@ -0,0 +1,36 @@
+//! Measures `str_width` with `UnicodeCompat::Unicode14` on emoji heavy input,
+//! where most characters are followed by a variation selector.
+//! Run with `cargo bench`.
+
+// benchmarks are only run on recent toolchains and are not bound by the MSRV
+#![allow(clippy::incompatible_msrv)]
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use grapheme_width::{str_width, UnicodeCompat};
+
+const ITERATIONS: u32 = 200;
+
+fn bench(name: &str, input: &str) {
+    // warmup
+    for _ in 0..ITERATIONS / 10 {
+        black_box(str_width(black_box(input), UnicodeCompat::Unicode14));
+    }
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(str_width(black_box(input), UnicodeCompat::Unicode14));
+    }
+    let elapsed = start.elapsed() / ITERATIONS;
+    let throughput = input.len() as f64 / elapsed.as_secs_f64() / (1024.0 * 1024.0);
+    println!("{name:<24} {elapsed:>12?}/iter {throughput:>10.1} MiB/s");
+}
+
+fn main() {
+    let variations = "☺\u{FE0F}✔\u{FE0E}❤\u{FE0F}⌚\u{FE0E}1\u{FE0F}\u{20E3}#\u{FE0F}\u{20E3}";
+    bench("variation selectors", &variations.repeat(20_000));
+    let emoji = "👍🏽👨\u{200D}👩\u{200D}👧🏳\u{FE0F}\u{200D}🌈😀🇩🇪";
+    bench("emoji sequences", &emoji.repeat(20_000));
+    let mixed = "status: ✔\u{FE0F} done ⏳ pending ☺\u{FE0E} 你好 ";
+    bench("mixed text", &mixed.repeat(20_000));
+}
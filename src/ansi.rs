@@ -0,0 +1,81 @@
+use crate::{str_width, UnicodeCompat};
+
+const ESC: u8 = 0x1b;
+const BEL: u8 = 0x07;
+
+/// Computes the width of a string that may contain ANSI escape sequences.
+///
+/// Escape sequences are not displayed by the terminal and therefore don't
+/// contribute to the width. The following sequences are recognized:
+///
+/// * Control sequences (CSI) like `ESC [ 1 ; 31 m` used for colors and styling.
+/// * Operating system commands (OSC) terminated by either BEL or ST (`ESC \`).
+///   This includes OSC 8 hyperlinks (`ESC ] 8 ; params ; URI ST text ESC ] 8 ; ; ST`)
+///   where only the link text is visible.
+/// * Other two character escape sequences like `ESC 7` or `ESC ( B`.
+///
+/// If an OSC is not terminated before the next escape sequence or the end of the
+/// string, only the introducer is ignored and the payload is measured like regular
+/// text. This ensures that a malformed sequence can not silently hide the rest of the string.
+pub fn str_width_ansi(s: &str, unicode_compat: UnicodeCompat) -> usize {
+    let bytes = s.as_bytes();
+    let mut width = 0;
+    let mut text_start = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != ESC {
+            i += 1;
+            continue;
+        }
+        width += str_width(&s[text_start..i], unicode_compat);
+        i += escape_sequence_len(&bytes[i..]);
+        text_start = i;
+    }
+    width + str_width(&s[text_start..], unicode_compat)
+}
+
+/// Returns the length of the escape sequence at the start of `bytes`.
+/// `bytes` must start with ESC. The returned length always ends at a char boundary
+/// because all bytes that terminate a sequence are ASCII.
+fn escape_sequence_len(bytes: &[u8]) -> usize {
+    debug_assert_eq!(bytes[0], ESC);
+    match bytes.get(1) {
+        Some(b'[') => {
+            // parameter and intermediate bytes followed by a single final byte
+            for (i, &byte) in bytes.iter().enumerate().skip(2) {
+                match byte {
+                    0x20..=0x3f => (),
+                    0x40..=0x7e => return i + 1,
+                    // malformed control sequence, abort at the invalid byte
+                    _ => return i,
+                }
+            }
+            bytes.len()
+        }
+        Some(b']') => {
+            for (i, &byte) in bytes.iter().enumerate().skip(2) {
+                match byte {
+                    BEL => return i + 1,
+                    ESC if bytes.get(i + 1) == Some(&b'\\') => return i + 2,
+                    ESC => break,
+                    _ => (),
+                }
+            }
+            // unterminated OSC: only skip the introducer
+            2
+        }
+        Some(0x20..=0x2f) => {
+            // intermediate bytes followed by a single final byte
+            let intermediates = bytes[1..]
+                .iter()
+                .take_while(|&&byte| matches!(byte, 0x20..=0x2f))
+                .count();
+            match bytes.get(1 + intermediates) {
+                Some(0x30..=0x7e) => intermediates + 2,
+                _ => intermediates + 1,
+            }
+        }
+        Some(0x30..=0x7e) => 2,
+        _ => 1,
+    }
+}
@@ -0,0 +1,43 @@
+use crate::{char_width_unicode9, str_width, UnicodeCompat, WidthOptions};
+
+/// Computes the width of (potentially invalid) UTF-8 encoded bytes.
+///
+/// Invalid sequences are replaced by the replacement character U+FFFD
+/// following the WHATWG replacement algorithm (each maximal invalid subpart
+/// is replaced by a single U+FFFD). This matches [`String::from_utf8_lossy`]
+/// but never allocates.
+pub fn bytes_width(bytes: &[u8], unicode_compat: UnicodeCompat) -> usize {
+    bytes_width_with(bytes, WidthOptions::new(unicode_compat))
+}
+
+/// Computes the width of (potentially invalid) UTF-8 encoded bytes like [`bytes_width`].
+///
+/// Each inserted replacement character is [`WidthOptions::replacement_width`] wide.
+/// The other options are ignored.
+pub fn bytes_width_with(mut bytes: &[u8], options: WidthOptions) -> usize {
+    let replacement_width = replacement_width(options);
+    let mut width = 0;
+    loop {
+        match core::str::from_utf8(bytes) {
+            Ok(valid) => return width + str_width(valid, options.compat),
+            Err(err) => {
+                let (valid, invalid) = bytes.split_at(err.valid_up_to());
+                // the prefix was just validated so this can never fail
+                let valid = core::str::from_utf8(valid).unwrap_or_default();
+                width += str_width(valid, options.compat) + replacement_width;
+                match err.error_len() {
+                    Some(len) => bytes = &invalid[len..],
+                    // incomplete sequence at the end of the input
+                    None => return width,
+                }
+            }
+        }
+    }
+}
+
+/// The width of a replacement character inserted for invalid input.
+pub(crate) fn replacement_width(options: WidthOptions) -> usize {
+    options
+        .replacement_width
+        .unwrap_or_else(|| char_width_unicode9(char::REPLACEMENT_CHARACTER))
+}
@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+
+use crate::{str_width, UnicodeCompat};
+
+/// A cache for the width of strings that are measured repeatedly.
+///
+/// Computing the width of a string is already fast. However, applications that
+/// re-measure the same labels very often (for example a table renderer that
+/// re-measures its headers every frame) can use this cache to avoid rescanning
+/// the text. Regular width calculation never allocates, so this cache is opt-in.
+///
+/// The cache stores a copy of every distinct string it measures, so a cached
+/// width is only returned for a string with exactly the same content. Lookups of
+/// strings that were measured before do not allocate. The cache grows with every
+/// distinct string, so it should only be used for a bounded set of labels.
+#[derive(Debug, Clone, Default)]
+pub struct WidthCache {
+    compat: UnicodeCompat,
+    map: HashMap<Box<str>, usize>,
+}
+
+impl WidthCache {
+    /// Creates an empty cache for the given compatability level.
+    pub fn new(unicode_compat: UnicodeCompat) -> WidthCache {
+        WidthCache {
+            compat: unicode_compat,
+            map: HashMap::new(),
+        }
+    }
+
+    /// Computes the width of `s` or returns the cached width if `s` was measured before.
+    pub fn width(&mut self, s: &str) -> usize {
+        if let Some(&width) = self.map.get(s) {
+            return width;
+        }
+        let width = str_width(s, self.compat);
+        self.map.insert(s.into(), width);
+        width
+    }
+
+    /// Removes all cached widths.
+    pub fn clear(&mut self) {
+        self.map.clear()
+    }
+}
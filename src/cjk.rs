@@ -0,0 +1,67 @@
+#[cfg(not(feature = "ascii-only"))]
+use crate::ambiguous::AMBIGUOUS_WIDTH;
+use crate::{char_width_indices, UnicodeCompat};
+
+/// Returns whether `c` has the east asian width property "ambiguous".
+/// Always returns `false` if the `ascii-only` feature is enabled.
+#[inline]
+pub(crate) fn is_ambiguous(c: char) -> bool {
+    #[cfg(not(feature = "ascii-only"))]
+    {
+        AMBIGUOUS_WIDTH.contains_char(c)
+    }
+    #[cfg(feature = "ascii-only")]
+    {
+        let _ = c;
+        false
+    }
+}
+
+/// The width of `c` in an east asian context: single width
+/// ambiguous characters are rendered double width.
+#[inline]
+fn ambiguous_wide(c: char, width: usize) -> usize {
+    if width == 1 && is_ambiguous(c) {
+        2
+    } else {
+        width
+    }
+}
+
+/// Computes the width of a string in an east asian (CJK) context.
+///
+/// Characters with ambiguous east asian width (for example `±`, `○` or greek and
+/// cyrillic letters) are rendered double width by terminals that are configured
+/// for CJK text. Otherwise this is equivalent to [`str_width`](crate::str_width).
+pub fn str_width_cjk(s: &str, unicode_compat: UnicodeCompat) -> usize {
+    char_width_indices(s, unicode_compat)
+        .map(|(_, c, width)| ambiguous_wide(c, width))
+        .sum()
+}
+
+/// Computes the width of a string where characters with ambiguous east asian width
+/// are only double width if they directly follow a double width character.
+///
+/// This is a heuristic for layout engines that select the width of ambiguous
+/// characters based on the surrounding text: an ambiguous character after a
+/// CJK (or any other double width) character is double width, including all ambiguous
+/// characters of the same run. Anywhere else, for example between two latin
+/// letters, ambiguous characters are single width. Zero width characters like
+/// combining marks do not interrupt a run.
+pub fn str_width_contextual(s: &str, unicode_compat: UnicodeCompat) -> usize {
+    let mut east_asian_context = false;
+    char_width_indices(s, unicode_compat)
+        .map(|(_, c, width)| {
+            if width == 0 {
+                return 0;
+            }
+            let width = if east_asian_context {
+                ambiguous_wide(c, width)
+            } else {
+                width
+            };
+            east_asian_context = width == 2;
+            width
+        })
+        .sum()
+}
@@ -0,0 +1,42 @@
+use core::fmt::{self, Write};
+
+use crate::tracker::{Advance, VariationState};
+use crate::UnicodeCompat;
+
+/// Computes the width of the text produced by formatting `value` with [`Display`](fmt::Display),
+/// like [`str_width`](crate::str_width) of `value.to_string()` but without allocating.
+pub fn display_width<D: fmt::Display + ?Sized>(value: &D, unicode_compat: UnicodeCompat) -> usize {
+    let mut counter = WidthCounter {
+        compat: unicode_compat,
+        width: 0,
+        variation: VariationState::new(),
+    };
+    // writing to a `WidthCounter` never fails, errors can only be caused
+    // by a faulty `Display` implementation and are ignored
+    let _ = write!(counter, "{value}");
+    counter.width
+}
+
+/// A [`fmt::Write`] implementation that sums up the width of the written text.
+///
+/// Variation selectors are resolved across calls to `write_str`,
+/// so the result does not depend on how the text is split up.
+struct WidthCounter {
+    compat: UnicodeCompat,
+    width: usize,
+    variation: VariationState,
+}
+
+impl Write for WidthCounter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            match self.variation.advance(c, self.compat) {
+                Advance::Width(width) => self.width += width,
+                Advance::Variation { base_width, width } => {
+                    self.width = self.width - base_width + width
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,7 +1,9 @@
 //! Generated by `cargo xtask gen-tables`, do not edit by hand.
 //! This file contains a UCD tri-set for determining whether an emojis presentation can be controlled with VS15/VS16.
 //! It was generated from UCD 15.0.0
+//! Source: local archive UCD.zip (SHA-256 15cfadb6bb6748fb78b9fd741102c3a191554b8fae12b74a795ceb189d963fa1)
 
+#[cfg(feature = "emoji")]
 pub(crate) const EMOJI_VARIATIONS: &'static ::ucd_trie::TrieSet = &::ucd_trie::TrieSet {
     tree1_level1: &[
         287953333581250560u64,
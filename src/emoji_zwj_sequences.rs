@@ -0,0 +1,1359 @@
+//! Generated by `cargo xtask gen-tables`, do not edit by hand.
+//! This file contains a sorted list of the emoji ZWJ sequences recommended for general interchange (RGI).
+//! It was generated from UCD 15.0.0
+//! Source: local archive UCD.zip (SHA-256 15cfadb6bb6748fb78b9fd741102c3a191554b8fae12b74a795ceb189d963fa1)
+
+#[doc = r" All RGI emoji ZWJ sequences (without VS16) sorted by codepoints"]
+#[cfg(all(feature = "emoji", not(feature = "ascii-only")))]
+pub(crate) const EMOJI_ZWJ_SEQUENCES: &[&str] = &[
+    "⛹\u{200d}♀",
+    "⛹\u{200d}♂",
+    "⛹🏻\u{200d}♀",
+    "⛹🏻\u{200d}♂",
+    "⛹🏼\u{200d}♀",
+    "⛹🏼\u{200d}♂",
+    "⛹🏽\u{200d}♀",
+    "⛹🏽\u{200d}♂",
+    "⛹🏾\u{200d}♀",
+    "⛹🏾\u{200d}♂",
+    "⛹🏿\u{200d}♀",
+    "⛹🏿\u{200d}♂",
+    "❤\u{200d}🔥",
+    "❤\u{200d}🩹",
+    "🏃\u{200d}♀",
+    "🏃\u{200d}♂",
+    "🏃🏻\u{200d}♀",
+    "🏃🏻\u{200d}♂",
+    "🏃🏼\u{200d}♀",
+    "🏃🏼\u{200d}♂",
+    "🏃🏽\u{200d}♀",
+    "🏃🏽\u{200d}♂",
+    "🏃🏾\u{200d}♀",
+    "🏃🏾\u{200d}♂",
+    "🏃🏿\u{200d}♀",
+    "🏃🏿\u{200d}♂",
+    "🏄\u{200d}♀",
+    "🏄\u{200d}♂",
+    "🏄🏻\u{200d}♀",
+    "🏄🏻\u{200d}♂",
+    "🏄🏼\u{200d}♀",
+    "🏄🏼\u{200d}♂",
+    "🏄🏽\u{200d}♀",
+    "🏄🏽\u{200d}♂",
+    "🏄🏾\u{200d}♀",
+    "🏄🏾\u{200d}♂",
+    "🏄🏿\u{200d}♀",
+    "🏄🏿\u{200d}♂",
+    "🏊\u{200d}♀",
+    "🏊\u{200d}♂",
+    "🏊🏻\u{200d}♀",
+    "🏊🏻\u{200d}♂",
+    "🏊🏼\u{200d}♀",
+    "🏊🏼\u{200d}♂",
+    "🏊🏽\u{200d}♀",
+    "🏊🏽\u{200d}♂",
+    "🏊🏾\u{200d}♀",
+    "🏊🏾\u{200d}♂",
+    "🏊🏿\u{200d}♀",
+    "🏊🏿\u{200d}♂",
+    "🏋\u{200d}♀",
+    "🏋\u{200d}♂",
+    "🏋🏻\u{200d}♀",
+    "🏋🏻\u{200d}♂",
+    "🏋🏼\u{200d}♀",
+    "🏋🏼\u{200d}♂",
+    "🏋🏽\u{200d}♀",
+    "🏋🏽\u{200d}♂",
+    "🏋🏾\u{200d}♀",
+    "🏋🏾\u{200d}♂",
+    "🏋🏿\u{200d}♀",
+    "🏋🏿\u{200d}♂",
+    "🏌\u{200d}♀",
+    "🏌\u{200d}♂",
+    "🏌🏻\u{200d}♀",
+    "🏌🏻\u{200d}♂",
+    "🏌🏼\u{200d}♀",
+    "🏌🏼\u{200d}♂",
+    "🏌🏽\u{200d}♀",
+    "🏌🏽\u{200d}♂",
+    "🏌🏾\u{200d}♀",
+    "🏌🏾\u{200d}♂",
+    "🏌🏿\u{200d}♀",
+    "🏌🏿\u{200d}♂",
+    "🏳\u{200d}⚧",
+    "🏳\u{200d}🌈",
+    "🏴\u{200d}☠",
+    "🐈\u{200d}⬛",
+    "🐕\u{200d}🦺",
+    "🐦\u{200d}⬛",
+    "🐻\u{200d}❄",
+    "👁\u{200d}🗨",
+    "👨\u{200d}⚕",
+    "👨\u{200d}⚖",
+    "👨\u{200d}✈",
+    "👨\u{200d}❤\u{200d}👨",
+    "👨\u{200d}❤\u{200d}💋\u{200d}👨",
+    "👨\u{200d}🌾",
+    "👨\u{200d}🍳",
+    "👨\u{200d}🍼",
+    "👨\u{200d}🎓",
+    "👨\u{200d}🎤",
+    "👨\u{200d}🎨",
+    "👨\u{200d}🏫",
+    "👨\u{200d}🏭",
+    "👨\u{200d}👦",
+    "👨\u{200d}👦\u{200d}👦",
+    "👨\u{200d}👧",
+    "👨\u{200d}👧\u{200d}👦",
+    "👨\u{200d}👧\u{200d}👧",
+    "👨\u{200d}👨\u{200d}👦",
+    "👨\u{200d}👨\u{200d}👦\u{200d}👦",
+    "👨\u{200d}👨\u{200d}👧",
+    "👨\u{200d}👨\u{200d}👧\u{200d}👦",
+    "👨\u{200d}👨\u{200d}👧\u{200d}👧",
+    "👨\u{200d}👩\u{200d}👦",
+    "👨\u{200d}👩\u{200d}👦\u{200d}👦",
+    "👨\u{200d}👩\u{200d}👧",
+    "👨\u{200d}👩\u{200d}👧\u{200d}👦",
+    "👨\u{200d}👩\u{200d}👧\u{200d}👧",
+    "👨\u{200d}💻",
+    "👨\u{200d}💼",
+    "👨\u{200d}🔧",
+    "👨\u{200d}🔬",
+    "👨\u{200d}🚀",
+    "👨\u{200d}🚒",
+    "👨\u{200d}🦯",
+    "👨\u{200d}🦰",
+    "👨\u{200d}🦱",
+    "👨\u{200d}🦲",
+    "👨\u{200d}🦳",
+    "👨\u{200d}🦼",
+    "👨\u{200d}🦽",
+    "👨🏻\u{200d}⚕",
+    "👨🏻\u{200d}⚖",
+    "👨🏻\u{200d}✈",
+    "👨🏻\u{200d}❤\u{200d}👨🏻",
+    "👨🏻\u{200d}❤\u{200d}👨🏼",
+    "👨🏻\u{200d}❤\u{200d}👨🏽",
+    "👨🏻\u{200d}❤\u{200d}👨🏾",
+    "👨🏻\u{200d}❤\u{200d}👨🏿",
+    "👨🏻\u{200d}❤\u{200d}💋\u{200d}👨🏻",
+    "👨🏻\u{200d}❤\u{200d}💋\u{200d}👨🏼",
+    "👨🏻\u{200d}❤\u{200d}💋\u{200d}👨🏽",
+    "👨🏻\u{200d}❤\u{200d}💋\u{200d}👨🏾",
+    "👨🏻\u{200d}❤\u{200d}💋\u{200d}👨🏿",
+    "👨🏻\u{200d}🌾",
+    "👨🏻\u{200d}🍳",
+    "👨🏻\u{200d}🍼",
+    "👨🏻\u{200d}🎓",
+    "👨🏻\u{200d}🎤",
+    "👨🏻\u{200d}🎨",
+    "👨🏻\u{200d}🏫",
+    "👨🏻\u{200d}🏭",
+    "👨🏻\u{200d}💻",
+    "👨🏻\u{200d}💼",
+    "👨🏻\u{200d}🔧",
+    "👨🏻\u{200d}🔬",
+    "👨🏻\u{200d}🚀",
+    "👨🏻\u{200d}🚒",
+    "👨🏻\u{200d}🤝\u{200d}👨🏼",
+    "👨🏻\u{200d}🤝\u{200d}👨🏽",
+    "👨🏻\u{200d}🤝\u{200d}👨🏾",
+    "👨🏻\u{200d}🤝\u{200d}👨🏿",
+    "👨🏻\u{200d}🦯",
+    "👨🏻\u{200d}🦰",
+    "👨🏻\u{200d}🦱",
+    "👨🏻\u{200d}🦲",
+    "👨🏻\u{200d}🦳",
+    "👨🏻\u{200d}🦼",
+    "👨🏻\u{200d}🦽",
+    "👨🏼\u{200d}⚕",
+    "👨🏼\u{200d}⚖",
+    "👨🏼\u{200d}✈",
+    "👨🏼\u{200d}❤\u{200d}👨🏻",
+    "👨🏼\u{200d}❤\u{200d}👨🏼",
+    "👨🏼\u{200d}❤\u{200d}👨🏽",
+    "👨🏼\u{200d}❤\u{200d}👨🏾",
+    "👨🏼\u{200d}❤\u{200d}👨🏿",
+    "👨🏼\u{200d}❤\u{200d}💋\u{200d}👨🏻",
+    "👨🏼\u{200d}❤\u{200d}💋\u{200d}👨🏼",
+    "👨🏼\u{200d}❤\u{200d}💋\u{200d}👨🏽",
+    "👨🏼\u{200d}❤\u{200d}💋\u{200d}👨🏾",
+    "👨🏼\u{200d}❤\u{200d}💋\u{200d}👨🏿",
+    "👨🏼\u{200d}🌾",
+    "👨🏼\u{200d}🍳",
+    "👨🏼\u{200d}🍼",
+    "👨🏼\u{200d}🎓",
+    "👨🏼\u{200d}🎤",
+    "👨🏼\u{200d}🎨",
+    "👨🏼\u{200d}🏫",
+    "👨🏼\u{200d}🏭",
+    "👨🏼\u{200d}💻",
+    "👨🏼\u{200d}💼",
+    "👨🏼\u{200d}🔧",
+    "👨🏼\u{200d}🔬",
+    "👨🏼\u{200d}🚀",
+    "👨🏼\u{200d}🚒",
+    "👨🏼\u{200d}🤝\u{200d}👨🏻",
+    "👨🏼\u{200d}🤝\u{200d}👨🏽",
+    "👨🏼\u{200d}🤝\u{200d}👨🏾",
+    "👨🏼\u{200d}🤝\u{200d}👨🏿",
+    "👨🏼\u{200d}🦯",
+    "👨🏼\u{200d}🦰",
+    "👨🏼\u{200d}🦱",
+    "👨🏼\u{200d}🦲",
+    "👨🏼\u{200d}🦳",
+    "👨🏼\u{200d}🦼",
+    "👨🏼\u{200d}🦽",
+    "👨🏽\u{200d}⚕",
+    "👨🏽\u{200d}⚖",
+    "👨🏽\u{200d}✈",
+    "👨🏽\u{200d}❤\u{200d}👨🏻",
+    "👨🏽\u{200d}❤\u{200d}👨🏼",
+    "👨🏽\u{200d}❤\u{200d}👨🏽",
+    "👨🏽\u{200d}❤\u{200d}👨🏾",
+    "👨🏽\u{200d}❤\u{200d}👨🏿",
+    "👨🏽\u{200d}❤\u{200d}💋\u{200d}👨🏻",
+    "👨🏽\u{200d}❤\u{200d}💋\u{200d}👨🏼",
+    "👨🏽\u{200d}❤\u{200d}💋\u{200d}👨🏽",
+    "👨🏽\u{200d}❤\u{200d}💋\u{200d}👨🏾",
+    "👨🏽\u{200d}❤\u{200d}💋\u{200d}👨🏿",
+    "👨🏽\u{200d}🌾",
+    "👨🏽\u{200d}🍳",
+    "👨🏽\u{200d}🍼",
+    "👨🏽\u{200d}🎓",
+    "👨🏽\u{200d}🎤",
+    "👨🏽\u{200d}🎨",
+    "👨🏽\u{200d}🏫",
+    "👨🏽\u{200d}🏭",
+    "👨🏽\u{200d}💻",
+    "👨🏽\u{200d}💼",
+    "👨🏽\u{200d}🔧",
+    "👨🏽\u{200d}🔬",
+    "👨🏽\u{200d}🚀",
+    "👨🏽\u{200d}🚒",
+    "👨🏽\u{200d}🤝\u{200d}👨🏻",
+    "👨🏽\u{200d}🤝\u{200d}👨🏼",
+    "👨🏽\u{200d}🤝\u{200d}👨🏾",
+    "👨🏽\u{200d}🤝\u{200d}👨🏿",
+    "👨🏽\u{200d}🦯",
+    "👨🏽\u{200d}🦰",
+    "👨🏽\u{200d}🦱",
+    "👨🏽\u{200d}🦲",
+    "👨🏽\u{200d}🦳",
+    "👨🏽\u{200d}🦼",
+    "👨🏽\u{200d}🦽",
+    "👨🏾\u{200d}⚕",
+    "👨🏾\u{200d}⚖",
+    "👨🏾\u{200d}✈",
+    "👨🏾\u{200d}❤\u{200d}👨🏻",
+    "👨🏾\u{200d}❤\u{200d}👨🏼",
+    "👨🏾\u{200d}❤\u{200d}👨🏽",
+    "👨🏾\u{200d}❤\u{200d}👨🏾",
+    "👨🏾\u{200d}❤\u{200d}👨🏿",
+    "👨🏾\u{200d}❤\u{200d}💋\u{200d}👨🏻",
+    "👨🏾\u{200d}❤\u{200d}💋\u{200d}👨🏼",
+    "👨🏾\u{200d}❤\u{200d}💋\u{200d}👨🏽",
+    "👨🏾\u{200d}❤\u{200d}💋\u{200d}👨🏾",
+    "👨🏾\u{200d}❤\u{200d}💋\u{200d}👨🏿",
+    "👨🏾\u{200d}🌾",
+    "👨🏾\u{200d}🍳",
+    "👨🏾\u{200d}🍼",
+    "👨🏾\u{200d}🎓",
+    "👨🏾\u{200d}🎤",
+    "👨🏾\u{200d}🎨",
+    "👨🏾\u{200d}🏫",
+    "👨🏾\u{200d}🏭",
+    "👨🏾\u{200d}💻",
+    "👨🏾\u{200d}💼",
+    "👨🏾\u{200d}🔧",
+    "👨🏾\u{200d}🔬",
+    "👨🏾\u{200d}🚀",
+    "👨🏾\u{200d}🚒",
+    "👨🏾\u{200d}🤝\u{200d}👨🏻",
+    "👨🏾\u{200d}🤝\u{200d}👨🏼",
+    "👨🏾\u{200d}🤝\u{200d}👨🏽",
+    "👨🏾\u{200d}🤝\u{200d}👨🏿",
+    "👨🏾\u{200d}🦯",
+    "👨🏾\u{200d}🦰",
+    "👨🏾\u{200d}🦱",
+    "👨🏾\u{200d}🦲",
+    "👨🏾\u{200d}🦳",
+    "👨🏾\u{200d}🦼",
+    "👨🏾\u{200d}🦽",
+    "👨🏿\u{200d}⚕",
+    "👨🏿\u{200d}⚖",
+    "👨🏿\u{200d}✈",
+    "👨🏿\u{200d}❤\u{200d}👨🏻",
+    "👨🏿\u{200d}❤\u{200d}👨🏼",
+    "👨🏿\u{200d}❤\u{200d}👨🏽",
+    "👨🏿\u{200d}❤\u{200d}👨🏾",
+    "👨🏿\u{200d}❤\u{200d}👨🏿",
+    "👨🏿\u{200d}❤\u{200d}💋\u{200d}👨🏻",
+    "👨🏿\u{200d}❤\u{200d}💋\u{200d}👨🏼",
+    "👨🏿\u{200d}❤\u{200d}💋\u{200d}👨🏽",
+    "👨🏿\u{200d}❤\u{200d}💋\u{200d}👨🏾",
+    "👨🏿\u{200d}❤\u{200d}💋\u{200d}👨🏿",
+    "👨🏿\u{200d}🌾",
+    "👨🏿\u{200d}🍳",
+    "👨🏿\u{200d}🍼",
+    "👨🏿\u{200d}🎓",
+    "👨🏿\u{200d}🎤",
+    "👨🏿\u{200d}🎨",
+    "👨🏿\u{200d}🏫",
+    "👨🏿\u{200d}🏭",
+    "👨🏿\u{200d}💻",
+    "👨🏿\u{200d}💼",
+    "👨🏿\u{200d}🔧",
+    "👨🏿\u{200d}🔬",
+    "👨🏿\u{200d}🚀",
+    "👨🏿\u{200d}🚒",
+    "👨🏿\u{200d}🤝\u{200d}👨🏻",
+    "👨🏿\u{200d}🤝\u{200d}👨🏼",
+    "👨🏿\u{200d}🤝\u{200d}👨🏽",
+    "👨🏿\u{200d}🤝\u{200d}👨🏾",
+    "👨🏿\u{200d}🦯",
+    "👨🏿\u{200d}🦰",
+    "👨🏿\u{200d}🦱",
+    "👨🏿\u{200d}🦲",
+    "👨🏿\u{200d}🦳",
+    "👨🏿\u{200d}🦼",
+    "👨🏿\u{200d}🦽",
+    "👩\u{200d}⚕",
+    "👩\u{200d}⚖",
+    "👩\u{200d}✈",
+    "👩\u{200d}❤\u{200d}👨",
+    "👩\u{200d}❤\u{200d}👩",
+    "👩\u{200d}❤\u{200d}💋\u{200d}👨",
+    "👩\u{200d}❤\u{200d}💋\u{200d}👩",
+    "👩\u{200d}🌾",
+    "👩\u{200d}🍳",
+    "👩\u{200d}🍼",
+    "👩\u{200d}🎓",
+    "👩\u{200d}🎤",
+    "👩\u{200d}🎨",
+    "👩\u{200d}🏫",
+    "👩\u{200d}🏭",
+    "👩\u{200d}👦",
+    "👩\u{200d}👦\u{200d}👦",
+    "👩\u{200d}👧",
+    "👩\u{200d}👧\u{200d}👦",
+    "👩\u{200d}👧\u{200d}👧",
+    "👩\u{200d}👩\u{200d}👦",
+    "👩\u{200d}👩\u{200d}👦\u{200d}👦",
+    "👩\u{200d}👩\u{200d}👧",
+    "👩\u{200d}👩\u{200d}👧\u{200d}👦",
+    "👩\u{200d}👩\u{200d}👧\u{200d}👧",
+    "👩\u{200d}💻",
+    "👩\u{200d}💼",
+    "👩\u{200d}🔧",
+    "👩\u{200d}🔬",
+    "👩\u{200d}🚀",
+    "👩\u{200d}🚒",
+    "👩\u{200d}🦯",
+    "👩\u{200d}🦰",
+    "👩\u{200d}🦱",
+    "👩\u{200d}🦲",
+    "👩\u{200d}🦳",
+    "👩\u{200d}🦼",
+    "👩\u{200d}🦽",
+    "👩🏻\u{200d}⚕",
+    "👩🏻\u{200d}⚖",
+    "👩🏻\u{200d}✈",
+    "👩🏻\u{200d}❤\u{200d}👨🏻",
+    "👩🏻\u{200d}❤\u{200d}👨🏼",
+    "👩🏻\u{200d}❤\u{200d}👨🏽",
+    "👩🏻\u{200d}❤\u{200d}👨🏾",
+    "👩🏻\u{200d}❤\u{200d}👨🏿",
+    "👩🏻\u{200d}❤\u{200d}👩🏻",
+    "👩🏻\u{200d}❤\u{200d}👩🏼",
+    "👩🏻\u{200d}❤\u{200d}👩🏽",
+    "👩🏻\u{200d}❤\u{200d}👩🏾",
+    "👩🏻\u{200d}❤\u{200d}👩🏿",
+    "👩🏻\u{200d}❤\u{200d}💋\u{200d}👨🏻",
+    "👩🏻\u{200d}❤\u{200d}💋\u{200d}👨🏼",
+    "👩🏻\u{200d}❤\u{200d}💋\u{200d}👨🏽",
+    "👩🏻\u{200d}❤\u{200d}💋\u{200d}👨🏾",
+    "👩🏻\u{200d}❤\u{200d}💋\u{200d}👨🏿",
+    "👩🏻\u{200d}❤\u{200d}💋\u{200d}👩🏻",
+    "👩🏻\u{200d}❤\u{200d}💋\u{200d}👩🏼",
+    "👩🏻\u{200d}❤\u{200d}💋\u{200d}👩🏽",
+    "👩🏻\u{200d}❤\u{200d}💋\u{200d}👩🏾",
+    "👩🏻\u{200d}❤\u{200d}💋\u{200d}👩🏿",
+    "👩🏻\u{200d}🌾",
+    "👩🏻\u{200d}🍳",
+    "👩🏻\u{200d}🍼",
+    "👩🏻\u{200d}🎓",
+    "👩🏻\u{200d}🎤",
+    "👩🏻\u{200d}🎨",
+    "👩🏻\u{200d}🏫",
+    "👩🏻\u{200d}🏭",
+    "👩🏻\u{200d}💻",
+    "👩🏻\u{200d}💼",
+    "👩🏻\u{200d}🔧",
+    "👩🏻\u{200d}🔬",
+    "👩🏻\u{200d}🚀",
+    "👩🏻\u{200d}🚒",
+    "👩🏻\u{200d}🤝\u{200d}👨🏼",
+    "👩🏻\u{200d}🤝\u{200d}👨🏽",
+    "👩🏻\u{200d}🤝\u{200d}👨🏾",
+    "👩🏻\u{200d}🤝\u{200d}👨🏿",
+    "👩🏻\u{200d}🤝\u{200d}👩🏼",
+    "👩🏻\u{200d}🤝\u{200d}👩🏽",
+    "👩🏻\u{200d}🤝\u{200d}👩🏾",
+    "👩🏻\u{200d}🤝\u{200d}👩🏿",
+    "👩🏻\u{200d}🦯",
+    "👩🏻\u{200d}🦰",
+    "👩🏻\u{200d}🦱",
+    "👩🏻\u{200d}🦲",
+    "👩🏻\u{200d}🦳",
+    "👩🏻\u{200d}🦼",
+    "👩🏻\u{200d}🦽",
+    "👩🏼\u{200d}⚕",
+    "👩🏼\u{200d}⚖",
+    "👩🏼\u{200d}✈",
+    "👩🏼\u{200d}❤\u{200d}👨🏻",
+    "👩🏼\u{200d}❤\u{200d}👨🏼",
+    "👩🏼\u{200d}❤\u{200d}👨🏽",
+    "👩🏼\u{200d}❤\u{200d}👨🏾",
+    "👩🏼\u{200d}❤\u{200d}👨🏿",
+    "👩🏼\u{200d}❤\u{200d}👩🏻",
+    "👩🏼\u{200d}❤\u{200d}👩🏼",
+    "👩🏼\u{200d}❤\u{200d}👩🏽",
+    "👩🏼\u{200d}❤\u{200d}👩🏾",
+    "👩🏼\u{200d}❤\u{200d}👩🏿",
+    "👩🏼\u{200d}❤\u{200d}💋\u{200d}👨🏻",
+    "👩🏼\u{200d}❤\u{200d}💋\u{200d}👨🏼",
+    "👩🏼\u{200d}❤\u{200d}💋\u{200d}👨🏽",
+    "👩🏼\u{200d}❤\u{200d}💋\u{200d}👨🏾",
+    "👩🏼\u{200d}❤\u{200d}💋\u{200d}👨🏿",
+    "👩🏼\u{200d}❤\u{200d}💋\u{200d}👩🏻",
+    "👩🏼\u{200d}❤\u{200d}💋\u{200d}👩🏼",
+    "👩🏼\u{200d}❤\u{200d}💋\u{200d}👩🏽",
+    "👩🏼\u{200d}❤\u{200d}💋\u{200d}👩🏾",
+    "👩🏼\u{200d}❤\u{200d}💋\u{200d}👩🏿",
+    "👩🏼\u{200d}🌾",
+    "👩🏼\u{200d}🍳",
+    "👩🏼\u{200d}🍼",
+    "👩🏼\u{200d}🎓",
+    "👩🏼\u{200d}🎤",
+    "👩🏼\u{200d}🎨",
+    "👩🏼\u{200d}🏫",
+    "👩🏼\u{200d}🏭",
+    "👩🏼\u{200d}💻",
+    "👩🏼\u{200d}💼",
+    "👩🏼\u{200d}🔧",
+    "👩🏼\u{200d}🔬",
+    "👩🏼\u{200d}🚀",
+    "👩🏼\u{200d}🚒",
+    "👩🏼\u{200d}🤝\u{200d}👨🏻",
+    "👩🏼\u{200d}🤝\u{200d}👨🏽",
+    "👩🏼\u{200d}🤝\u{200d}👨🏾",
+    "👩🏼\u{200d}🤝\u{200d}👨🏿",
+    "👩🏼\u{200d}🤝\u{200d}👩🏻",
+    "👩🏼\u{200d}🤝\u{200d}👩🏽",
+    "👩🏼\u{200d}🤝\u{200d}👩🏾",
+    "👩🏼\u{200d}🤝\u{200d}👩🏿",
+    "👩🏼\u{200d}🦯",
+    "👩🏼\u{200d}🦰",
+    "👩🏼\u{200d}🦱",
+    "👩🏼\u{200d}🦲",
+    "👩🏼\u{200d}🦳",
+    "👩🏼\u{200d}🦼",
+    "👩🏼\u{200d}🦽",
+    "👩🏽\u{200d}⚕",
+    "👩🏽\u{200d}⚖",
+    "👩🏽\u{200d}✈",
+    "👩🏽\u{200d}❤\u{200d}👨🏻",
+    "👩🏽\u{200d}❤\u{200d}👨🏼",
+    "👩🏽\u{200d}❤\u{200d}👨🏽",
+    "👩🏽\u{200d}❤\u{200d}👨🏾",
+    "👩🏽\u{200d}❤\u{200d}👨🏿",
+    "👩🏽\u{200d}❤\u{200d}👩🏻",
+    "👩🏽\u{200d}❤\u{200d}👩🏼",
+    "👩🏽\u{200d}❤\u{200d}👩🏽",
+    "👩🏽\u{200d}❤\u{200d}👩🏾",
+    "👩🏽\u{200d}❤\u{200d}👩🏿",
+    "👩🏽\u{200d}❤\u{200d}💋\u{200d}👨🏻",
+    "👩🏽\u{200d}❤\u{200d}💋\u{200d}👨🏼",
+    "👩🏽\u{200d}❤\u{200d}💋\u{200d}👨🏽",
+    "👩🏽\u{200d}❤\u{200d}💋\u{200d}👨🏾",
+    "👩🏽\u{200d}❤\u{200d}💋\u{200d}👨🏿",
+    "👩🏽\u{200d}❤\u{200d}💋\u{200d}👩🏻",
+    "👩🏽\u{200d}❤\u{200d}💋\u{200d}👩🏼",
+    "👩🏽\u{200d}❤\u{200d}💋\u{200d}👩🏽",
+    "👩🏽\u{200d}❤\u{200d}💋\u{200d}👩🏾",
+    "👩🏽\u{200d}❤\u{200d}💋\u{200d}👩🏿",
+    "👩🏽\u{200d}🌾",
+    "👩🏽\u{200d}🍳",
+    "👩🏽\u{200d}🍼",
+    "👩🏽\u{200d}🎓",
+    "👩🏽\u{200d}🎤",
+    "👩🏽\u{200d}🎨",
+    "👩🏽\u{200d}🏫",
+    "👩🏽\u{200d}🏭",
+    "👩🏽\u{200d}💻",
+    "👩🏽\u{200d}💼",
+    "👩🏽\u{200d}🔧",
+    "👩🏽\u{200d}🔬",
+    "👩🏽\u{200d}🚀",
+    "👩🏽\u{200d}🚒",
+    "👩🏽\u{200d}🤝\u{200d}👨🏻",
+    "👩🏽\u{200d}🤝\u{200d}👨🏼",
+    "👩🏽\u{200d}🤝\u{200d}👨🏾",
+    "👩🏽\u{200d}🤝\u{200d}👨🏿",
+    "👩🏽\u{200d}🤝\u{200d}👩🏻",
+    "👩🏽\u{200d}🤝\u{200d}👩🏼",
+    "👩🏽\u{200d}🤝\u{200d}👩🏾",
+    "👩🏽\u{200d}🤝\u{200d}👩🏿",
+    "👩🏽\u{200d}🦯",
+    "👩🏽\u{200d}🦰",
+    "👩🏽\u{200d}🦱",
+    "👩🏽\u{200d}🦲",
+    "👩🏽\u{200d}🦳",
+    "👩🏽\u{200d}🦼",
+    "👩🏽\u{200d}🦽",
+    "👩🏾\u{200d}⚕",
+    "👩🏾\u{200d}⚖",
+    "👩🏾\u{200d}✈",
+    "👩🏾\u{200d}❤\u{200d}👨🏻",
+    "👩🏾\u{200d}❤\u{200d}👨🏼",
+    "👩🏾\u{200d}❤\u{200d}👨🏽",
+    "👩🏾\u{200d}❤\u{200d}👨🏾",
+    "👩🏾\u{200d}❤\u{200d}👨🏿",
+    "👩🏾\u{200d}❤\u{200d}👩🏻",
+    "👩🏾\u{200d}❤\u{200d}👩🏼",
+    "👩🏾\u{200d}❤\u{200d}👩🏽",
+    "👩🏾\u{200d}❤\u{200d}👩🏾",
+    "👩🏾\u{200d}❤\u{200d}👩🏿",
+    "👩🏾\u{200d}❤\u{200d}💋\u{200d}👨🏻",
+    "👩🏾\u{200d}❤\u{200d}💋\u{200d}👨🏼",
+    "👩🏾\u{200d}❤\u{200d}💋\u{200d}👨🏽",
+    "👩🏾\u{200d}❤\u{200d}💋\u{200d}👨🏾",
+    "👩🏾\u{200d}❤\u{200d}💋\u{200d}👨🏿",
+    "👩🏾\u{200d}❤\u{200d}💋\u{200d}👩🏻",
+    "👩🏾\u{200d}❤\u{200d}💋\u{200d}👩🏼",
+    "👩🏾\u{200d}❤\u{200d}💋\u{200d}👩🏽",
+    "👩🏾\u{200d}❤\u{200d}💋\u{200d}👩🏾",
+    "👩🏾\u{200d}❤\u{200d}💋\u{200d}👩🏿",
+    "👩🏾\u{200d}🌾",
+    "👩🏾\u{200d}🍳",
+    "👩🏾\u{200d}🍼",
+    "👩🏾\u{200d}🎓",
+    "👩🏾\u{200d}🎤",
+    "👩🏾\u{200d}🎨",
+    "👩🏾\u{200d}🏫",
+    "👩🏾\u{200d}🏭",
+    "👩🏾\u{200d}💻",
+    "👩🏾\u{200d}💼",
+    "👩🏾\u{200d}🔧",
+    "👩🏾\u{200d}🔬",
+    "👩🏾\u{200d}🚀",
+    "👩🏾\u{200d}🚒",
+    "👩🏾\u{200d}🤝\u{200d}👨🏻",
+    "👩🏾\u{200d}🤝\u{200d}👨🏼",
+    "👩🏾\u{200d}🤝\u{200d}👨🏽",
+    "👩🏾\u{200d}🤝\u{200d}👨🏿",
+    "👩🏾\u{200d}🤝\u{200d}👩🏻",
+    "👩🏾\u{200d}🤝\u{200d}👩🏼",
+    "👩🏾\u{200d}🤝\u{200d}👩🏽",
+    "👩🏾\u{200d}🤝\u{200d}👩🏿",
+    "👩🏾\u{200d}🦯",
+    "👩🏾\u{200d}🦰",
+    "👩🏾\u{200d}🦱",
+    "👩🏾\u{200d}🦲",
+    "👩🏾\u{200d}🦳",
+    "👩🏾\u{200d}🦼",
+    "👩🏾\u{200d}🦽",
+    "👩🏿\u{200d}⚕",
+    "👩🏿\u{200d}⚖",
+    "👩🏿\u{200d}✈",
+    "👩🏿\u{200d}❤\u{200d}👨🏻",
+    "👩🏿\u{200d}❤\u{200d}👨🏼",
+    "👩🏿\u{200d}❤\u{200d}👨🏽",
+    "👩🏿\u{200d}❤\u{200d}👨🏾",
+    "👩🏿\u{200d}❤\u{200d}👨🏿",
+    "👩🏿\u{200d}❤\u{200d}👩🏻",
+    "👩🏿\u{200d}❤\u{200d}👩🏼",
+    "👩🏿\u{200d}❤\u{200d}👩🏽",
+    "👩🏿\u{200d}❤\u{200d}👩🏾",
+    "👩🏿\u{200d}❤\u{200d}👩🏿",
+    "👩🏿\u{200d}❤\u{200d}💋\u{200d}👨🏻",
+    "👩🏿\u{200d}❤\u{200d}💋\u{200d}👨🏼",
+    "👩🏿\u{200d}❤\u{200d}💋\u{200d}👨🏽",
+    "👩🏿\u{200d}❤\u{200d}💋\u{200d}👨🏾",
+    "👩🏿\u{200d}❤\u{200d}💋\u{200d}👨🏿",
+    "👩🏿\u{200d}❤\u{200d}💋\u{200d}👩🏻",
+    "👩🏿\u{200d}❤\u{200d}💋\u{200d}👩🏼",
+    "👩🏿\u{200d}❤\u{200d}💋\u{200d}👩🏽",
+    "👩🏿\u{200d}❤\u{200d}💋\u{200d}👩🏾",
+    "👩🏿\u{200d}❤\u{200d}💋\u{200d}👩🏿",
+    "👩🏿\u{200d}🌾",
+    "👩🏿\u{200d}🍳",
+    "👩🏿\u{200d}🍼",
+    "👩🏿\u{200d}🎓",
+    "👩🏿\u{200d}🎤",
+    "👩🏿\u{200d}🎨",
+    "👩🏿\u{200d}🏫",
+    "👩🏿\u{200d}🏭",
+    "👩🏿\u{200d}💻",
+    "👩🏿\u{200d}💼",
+    "👩🏿\u{200d}🔧",
+    "👩🏿\u{200d}🔬",
+    "👩🏿\u{200d}🚀",
+    "👩🏿\u{200d}🚒",
+    "👩🏿\u{200d}🤝\u{200d}👨🏻",
+    "👩🏿\u{200d}🤝\u{200d}👨🏼",
+    "👩🏿\u{200d}🤝\u{200d}👨🏽",
+    "👩🏿\u{200d}🤝\u{200d}👨🏾",
+    "👩🏿\u{200d}🤝\u{200d}👩🏻",
+    "👩🏿\u{200d}🤝\u{200d}👩🏼",
+    "👩🏿\u{200d}🤝\u{200d}👩🏽",
+    "👩🏿\u{200d}🤝\u{200d}👩🏾",
+    "👩🏿\u{200d}🦯",
+    "👩🏿\u{200d}🦰",
+    "👩🏿\u{200d}🦱",
+    "👩🏿\u{200d}🦲",
+    "👩🏿\u{200d}🦳",
+    "👩🏿\u{200d}🦼",
+    "👩🏿\u{200d}🦽",
+    "👮\u{200d}♀",
+    "👮\u{200d}♂",
+    "👮🏻\u{200d}♀",
+    "👮🏻\u{200d}♂",
+    "👮🏼\u{200d}♀",
+    "👮🏼\u{200d}♂",
+    "👮🏽\u{200d}♀",
+    "👮🏽\u{200d}♂",
+    "👮🏾\u{200d}♀",
+    "👮🏾\u{200d}♂",
+    "👮🏿\u{200d}♀",
+    "👮🏿\u{200d}♂",
+    "👯\u{200d}♀",
+    "👯\u{200d}♂",
+    "👰\u{200d}♀",
+    "👰\u{200d}♂",
+    "👰🏻\u{200d}♀",
+    "👰🏻\u{200d}♂",
+    "👰🏼\u{200d}♀",
+    "👰🏼\u{200d}♂",
+    "👰🏽\u{200d}♀",
+    "👰🏽\u{200d}♂",
+    "👰🏾\u{200d}♀",
+    "👰🏾\u{200d}♂",
+    "👰🏿\u{200d}♀",
+    "👰🏿\u{200d}♂",
+    "👱\u{200d}♀",
+    "👱\u{200d}♂",
+    "👱🏻\u{200d}♀",
+    "👱🏻\u{200d}♂",
+    "👱🏼\u{200d}♀",
+    "👱🏼\u{200d}♂",
+    "👱🏽\u{200d}♀",
+    "👱🏽\u{200d}♂",
+    "👱🏾\u{200d}♀",
+    "👱🏾\u{200d}♂",
+    "👱🏿\u{200d}♀",
+    "👱🏿\u{200d}♂",
+    "👳\u{200d}♀",
+    "👳\u{200d}♂",
+    "👳🏻\u{200d}♀",
+    "👳🏻\u{200d}♂",
+    "👳🏼\u{200d}♀",
+    "👳🏼\u{200d}♂",
+    "👳🏽\u{200d}♀",
+    "👳🏽\u{200d}♂",
+    "👳🏾\u{200d}♀",
+    "👳🏾\u{200d}♂",
+    "👳🏿\u{200d}♀",
+    "👳🏿\u{200d}♂",
+    "👷\u{200d}♀",
+    "👷\u{200d}♂",
+    "👷🏻\u{200d}♀",
+    "👷🏻\u{200d}♂",
+    "👷🏼\u{200d}♀",
+    "👷🏼\u{200d}♂",
+    "👷🏽\u{200d}♀",
+    "👷🏽\u{200d}♂",
+    "👷🏾\u{200d}♀",
+    "👷🏾\u{200d}♂",
+    "👷🏿\u{200d}♀",
+    "👷🏿\u{200d}♂",
+    "💁\u{200d}♀",
+    "💁\u{200d}♂",
+    "💁🏻\u{200d}♀",
+    "💁🏻\u{200d}♂",
+    "💁🏼\u{200d}♀",
+    "💁🏼\u{200d}♂",
+    "💁🏽\u{200d}♀",
+    "💁🏽\u{200d}♂",
+    "💁🏾\u{200d}♀",
+    "💁🏾\u{200d}♂",
+    "💁🏿\u{200d}♀",
+    "💁🏿\u{200d}♂",
+    "💂\u{200d}♀",
+    "💂\u{200d}♂",
+    "💂🏻\u{200d}♀",
+    "💂🏻\u{200d}♂",
+    "💂🏼\u{200d}♀",
+    "💂🏼\u{200d}♂",
+    "💂🏽\u{200d}♀",
+    "💂🏽\u{200d}♂",
+    "💂🏾\u{200d}♀",
+    "💂🏾\u{200d}♂",
+    "💂🏿\u{200d}♀",
+    "💂🏿\u{200d}♂",
+    "💆\u{200d}♀",
+    "💆\u{200d}♂",
+    "💆🏻\u{200d}♀",
+    "💆🏻\u{200d}♂",
+    "💆🏼\u{200d}♀",
+    "💆🏼\u{200d}♂",
+    "💆🏽\u{200d}♀",
+    "💆🏽\u{200d}♂",
+    "💆🏾\u{200d}♀",
+    "💆🏾\u{200d}♂",
+    "💆🏿\u{200d}♀",
+    "💆🏿\u{200d}♂",
+    "💇\u{200d}♀",
+    "💇\u{200d}♂",
+    "💇🏻\u{200d}♀",
+    "💇🏻\u{200d}♂",
+    "💇🏼\u{200d}♀",
+    "💇🏼\u{200d}♂",
+    "💇🏽\u{200d}♀",
+    "💇🏽\u{200d}♂",
+    "💇🏾\u{200d}♀",
+    "💇🏾\u{200d}♂",
+    "💇🏿\u{200d}♀",
+    "💇🏿\u{200d}♂",
+    "🕵\u{200d}♀",
+    "🕵\u{200d}♂",
+    "🕵🏻\u{200d}♀",
+    "🕵🏻\u{200d}♂",
+    "🕵🏼\u{200d}♀",
+    "🕵🏼\u{200d}♂",
+    "🕵🏽\u{200d}♀",
+    "🕵🏽\u{200d}♂",
+    "🕵🏾\u{200d}♀",
+    "🕵🏾\u{200d}♂",
+    "🕵🏿\u{200d}♀",
+    "🕵🏿\u{200d}♂",
+    "😮\u{200d}💨",
+    "😵\u{200d}💫",
+    "😶\u{200d}🌫",
+    "🙅\u{200d}♀",
+    "🙅\u{200d}♂",
+    "🙅🏻\u{200d}♀",
+    "🙅🏻\u{200d}♂",
+    "🙅🏼\u{200d}♀",
+    "🙅🏼\u{200d}♂",
+    "🙅🏽\u{200d}♀",
+    "🙅🏽\u{200d}♂",
+    "🙅🏾\u{200d}♀",
+    "🙅🏾\u{200d}♂",
+    "🙅🏿\u{200d}♀",
+    "🙅🏿\u{200d}♂",
+    "🙆\u{200d}♀",
+    "🙆\u{200d}♂",
+    "🙆🏻\u{200d}♀",
+    "🙆🏻\u{200d}♂",
+    "🙆🏼\u{200d}♀",
+    "🙆🏼\u{200d}♂",
+    "🙆🏽\u{200d}♀",
+    "🙆🏽\u{200d}♂",
+    "🙆🏾\u{200d}♀",
+    "🙆🏾\u{200d}♂",
+    "🙆🏿\u{200d}♀",
+    "🙆🏿\u{200d}♂",
+    "🙇\u{200d}♀",
+    "🙇\u{200d}♂",
+    "🙇🏻\u{200d}♀",
+    "🙇🏻\u{200d}♂",
+    "🙇🏼\u{200d}♀",
+    "🙇🏼\u{200d}♂",
+    "🙇🏽\u{200d}♀",
+    "🙇🏽\u{200d}♂",
+    "🙇🏾\u{200d}♀",
+    "🙇🏾\u{200d}♂",
+    "🙇🏿\u{200d}♀",
+    "🙇🏿\u{200d}♂",
+    "🙋\u{200d}♀",
+    "🙋\u{200d}♂",
+    "🙋🏻\u{200d}♀",
+    "🙋🏻\u{200d}♂",
+    "🙋🏼\u{200d}♀",
+    "🙋🏼\u{200d}♂",
+    "🙋🏽\u{200d}♀",
+    "🙋🏽\u{200d}♂",
+    "🙋🏾\u{200d}♀",
+    "🙋🏾\u{200d}♂",
+    "🙋🏿\u{200d}♀",
+    "🙋🏿\u{200d}♂",
+    "🙍\u{200d}♀",
+    "🙍\u{200d}♂",
+    "🙍🏻\u{200d}♀",
+    "🙍🏻\u{200d}♂",
+    "🙍🏼\u{200d}♀",
+    "🙍🏼\u{200d}♂",
+    "🙍🏽\u{200d}♀",
+    "🙍🏽\u{200d}♂",
+    "🙍🏾\u{200d}♀",
+    "🙍🏾\u{200d}♂",
+    "🙍🏿\u{200d}♀",
+    "🙍🏿\u{200d}♂",
+    "🙎\u{200d}♀",
+    "🙎\u{200d}♂",
+    "🙎🏻\u{200d}♀",
+    "🙎🏻\u{200d}♂",
+    "🙎🏼\u{200d}♀",
+    "🙎🏼\u{200d}♂",
+    "🙎🏽\u{200d}♀",
+    "🙎🏽\u{200d}♂",
+    "🙎🏾\u{200d}♀",
+    "🙎🏾\u{200d}♂",
+    "🙎🏿\u{200d}♀",
+    "🙎🏿\u{200d}♂",
+    "🚣\u{200d}♀",
+    "🚣\u{200d}♂",
+    "🚣🏻\u{200d}♀",
+    "🚣🏻\u{200d}♂",
+    "🚣🏼\u{200d}♀",
+    "🚣🏼\u{200d}♂",
+    "🚣🏽\u{200d}♀",
+    "🚣🏽\u{200d}♂",
+    "🚣🏾\u{200d}♀",
+    "🚣🏾\u{200d}♂",
+    "🚣🏿\u{200d}♀",
+    "🚣🏿\u{200d}♂",
+    "🚴\u{200d}♀",
+    "🚴\u{200d}♂",
+    "🚴🏻\u{200d}♀",
+    "🚴🏻\u{200d}♂",
+    "🚴🏼\u{200d}♀",
+    "🚴🏼\u{200d}♂",
+    "🚴🏽\u{200d}♀",
+    "🚴🏽\u{200d}♂",
+    "🚴🏾\u{200d}♀",
+    "🚴🏾\u{200d}♂",
+    "🚴🏿\u{200d}♀",
+    "🚴🏿\u{200d}♂",
+    "🚵\u{200d}♀",
+    "🚵\u{200d}♂",
+    "🚵🏻\u{200d}♀",
+    "🚵🏻\u{200d}♂",
+    "🚵🏼\u{200d}♀",
+    "🚵🏼\u{200d}♂",
+    "🚵🏽\u{200d}♀",
+    "🚵🏽\u{200d}♂",
+    "🚵🏾\u{200d}♀",
+    "🚵🏾\u{200d}♂",
+    "🚵🏿\u{200d}♀",
+    "🚵🏿\u{200d}♂",
+    "🚶\u{200d}♀",
+    "🚶\u{200d}♂",
+    "🚶🏻\u{200d}♀",
+    "🚶🏻\u{200d}♂",
+    "🚶🏼\u{200d}♀",
+    "🚶🏼\u{200d}♂",
+    "🚶🏽\u{200d}♀",
+    "🚶🏽\u{200d}♂",
+    "🚶🏾\u{200d}♀",
+    "🚶🏾\u{200d}♂",
+    "🚶🏿\u{200d}♀",
+    "🚶🏿\u{200d}♂",
+    "🤦\u{200d}♀",
+    "🤦\u{200d}♂",
+    "🤦🏻\u{200d}♀",
+    "🤦🏻\u{200d}♂",
+    "🤦🏼\u{200d}♀",
+    "🤦🏼\u{200d}♂",
+    "🤦🏽\u{200d}♀",
+    "🤦🏽\u{200d}♂",
+    "🤦🏾\u{200d}♀",
+    "🤦🏾\u{200d}♂",
+    "🤦🏿\u{200d}♀",
+    "🤦🏿\u{200d}♂",
+    "🤵\u{200d}♀",
+    "🤵\u{200d}♂",
+    "🤵🏻\u{200d}♀",
+    "🤵🏻\u{200d}♂",
+    "🤵🏼\u{200d}♀",
+    "🤵🏼\u{200d}♂",
+    "🤵🏽\u{200d}♀",
+    "🤵🏽\u{200d}♂",
+    "🤵🏾\u{200d}♀",
+    "🤵🏾\u{200d}♂",
+    "🤵🏿\u{200d}♀",
+    "🤵🏿\u{200d}♂",
+    "🤷\u{200d}♀",
+    "🤷\u{200d}♂",
+    "🤷🏻\u{200d}♀",
+    "🤷🏻\u{200d}♂",
+    "🤷🏼\u{200d}♀",
+    "🤷🏼\u{200d}♂",
+    "🤷🏽\u{200d}♀",
+    "🤷🏽\u{200d}♂",
+    "🤷🏾\u{200d}♀",
+    "🤷🏾\u{200d}♂",
+    "🤷🏿\u{200d}♀",
+    "🤷🏿\u{200d}♂",
+    "🤸\u{200d}♀",
+    "🤸\u{200d}♂",
+    "🤸🏻\u{200d}♀",
+    "🤸🏻\u{200d}♂",
+    "🤸🏼\u{200d}♀",
+    "🤸🏼\u{200d}♂",
+    "🤸🏽\u{200d}♀",
+    "🤸🏽\u{200d}♂",
+    "🤸🏾\u{200d}♀",
+    "🤸🏾\u{200d}♂",
+    "🤸🏿\u{200d}♀",
+    "🤸🏿\u{200d}♂",
+    "🤹\u{200d}♀",
+    "🤹\u{200d}♂",
+    "🤹🏻\u{200d}♀",
+    "🤹🏻\u{200d}♂",
+    "🤹🏼\u{200d}♀",
+    "🤹🏼\u{200d}♂",
+    "🤹🏽\u{200d}♀",
+    "🤹🏽\u{200d}♂",
+    "🤹🏾\u{200d}♀",
+    "🤹🏾\u{200d}♂",
+    "🤹🏿\u{200d}♀",
+    "🤹🏿\u{200d}♂",
+    "🤼\u{200d}♀",
+    "🤼\u{200d}♂",
+    "🤽\u{200d}♀",
+    "🤽\u{200d}♂",
+    "🤽🏻\u{200d}♀",
+    "🤽🏻\u{200d}♂",
+    "🤽🏼\u{200d}♀",
+    "🤽🏼\u{200d}♂",
+    "🤽🏽\u{200d}♀",
+    "🤽🏽\u{200d}♂",
+    "🤽🏾\u{200d}♀",
+    "🤽🏾\u{200d}♂",
+    "🤽🏿\u{200d}♀",
+    "🤽🏿\u{200d}♂",
+    "🤾\u{200d}♀",
+    "🤾\u{200d}♂",
+    "🤾🏻\u{200d}♀",
+    "🤾🏻\u{200d}♂",
+    "🤾🏼\u{200d}♀",
+    "🤾🏼\u{200d}♂",
+    "🤾🏽\u{200d}♀",
+    "🤾🏽\u{200d}♂",
+    "🤾🏾\u{200d}♀",
+    "🤾🏾\u{200d}♂",
+    "🤾🏿\u{200d}♀",
+    "🤾🏿\u{200d}♂",
+    "🦸\u{200d}♀",
+    "🦸\u{200d}♂",
+    "🦸🏻\u{200d}♀",
+    "🦸🏻\u{200d}♂",
+    "🦸🏼\u{200d}♀",
+    "🦸🏼\u{200d}♂",
+    "🦸🏽\u{200d}♀",
+    "🦸🏽\u{200d}♂",
+    "🦸🏾\u{200d}♀",
+    "🦸🏾\u{200d}♂",
+    "🦸🏿\u{200d}♀",
+    "🦸🏿\u{200d}♂",
+    "🦹\u{200d}♀",
+    "🦹\u{200d}♂",
+    "🦹🏻\u{200d}♀",
+    "🦹🏻\u{200d}♂",
+    "🦹🏼\u{200d}♀",
+    "🦹🏼\u{200d}♂",
+    "🦹🏽\u{200d}♀",
+    "🦹🏽\u{200d}♂",
+    "🦹🏾\u{200d}♀",
+    "🦹🏾\u{200d}♂",
+    "🦹🏿\u{200d}♀",
+    "🦹🏿\u{200d}♂",
+    "🧍\u{200d}♀",
+    "🧍\u{200d}♂",
+    "🧍🏻\u{200d}♀",
+    "🧍🏻\u{200d}♂",
+    "🧍🏼\u{200d}♀",
+    "🧍🏼\u{200d}♂",
+    "🧍🏽\u{200d}♀",
+    "🧍🏽\u{200d}♂",
+    "🧍🏾\u{200d}♀",
+    "🧍🏾\u{200d}♂",
+    "🧍🏿\u{200d}♀",
+    "🧍🏿\u{200d}♂",
+    "🧎\u{200d}♀",
+    "🧎\u{200d}♂",
+    "🧎🏻\u{200d}♀",
+    "🧎🏻\u{200d}♂",
+    "🧎🏼\u{200d}♀",
+    "🧎🏼\u{200d}♂",
+    "🧎🏽\u{200d}♀",
+    "🧎🏽\u{200d}♂",
+    "🧎🏾\u{200d}♀",
+    "🧎🏾\u{200d}♂",
+    "🧎🏿\u{200d}♀",
+    "🧎🏿\u{200d}♂",
+    "🧏\u{200d}♀",
+    "🧏\u{200d}♂",
+    "🧏🏻\u{200d}♀",
+    "🧏🏻\u{200d}♂",
+    "🧏🏼\u{200d}♀",
+    "🧏🏼\u{200d}♂",
+    "🧏🏽\u{200d}♀",
+    "🧏🏽\u{200d}♂",
+    "🧏🏾\u{200d}♀",
+    "🧏🏾\u{200d}♂",
+    "🧏🏿\u{200d}♀",
+    "🧏🏿\u{200d}♂",
+    "🧑\u{200d}⚕",
+    "🧑\u{200d}⚖",
+    "🧑\u{200d}✈",
+    "🧑\u{200d}🌾",
+    "🧑\u{200d}🍳",
+    "🧑\u{200d}🍼",
+    "🧑\u{200d}🎄",
+    "🧑\u{200d}🎓",
+    "🧑\u{200d}🎤",
+    "🧑\u{200d}🎨",
+    "🧑\u{200d}🏫",
+    "🧑\u{200d}🏭",
+    "🧑\u{200d}💻",
+    "🧑\u{200d}💼",
+    "🧑\u{200d}🔧",
+    "🧑\u{200d}🔬",
+    "🧑\u{200d}🚀",
+    "🧑\u{200d}🚒",
+    "🧑\u{200d}🤝\u{200d}🧑",
+    "🧑\u{200d}🦯",
+    "🧑\u{200d}🦰",
+    "🧑\u{200d}🦱",
+    "🧑\u{200d}🦲",
+    "🧑\u{200d}🦳",
+    "🧑\u{200d}🦼",
+    "🧑\u{200d}🦽",
+    "🧑🏻\u{200d}⚕",
+    "🧑🏻\u{200d}⚖",
+    "🧑🏻\u{200d}✈",
+    "🧑🏻\u{200d}❤\u{200d}💋\u{200d}🧑🏼",
+    "🧑🏻\u{200d}❤\u{200d}💋\u{200d}🧑🏽",
+    "🧑🏻\u{200d}❤\u{200d}💋\u{200d}🧑🏾",
+    "🧑🏻\u{200d}❤\u{200d}💋\u{200d}🧑🏿",
+    "🧑🏻\u{200d}❤\u{200d}🧑🏼",
+    "🧑🏻\u{200d}❤\u{200d}🧑🏽",
+    "🧑🏻\u{200d}❤\u{200d}🧑🏾",
+    "🧑🏻\u{200d}❤\u{200d}🧑🏿",
+    "🧑🏻\u{200d}🌾",
+    "🧑🏻\u{200d}🍳",
+    "🧑🏻\u{200d}🍼",
+    "🧑🏻\u{200d}🎄",
+    "🧑🏻\u{200d}🎓",
+    "🧑🏻\u{200d}🎤",
+    "🧑🏻\u{200d}🎨",
+    "🧑🏻\u{200d}🏫",
+    "🧑🏻\u{200d}🏭",
+    "🧑🏻\u{200d}💻",
+    "🧑🏻\u{200d}💼",
+    "🧑🏻\u{200d}🔧",
+    "🧑🏻\u{200d}🔬",
+    "🧑🏻\u{200d}🚀",
+    "🧑🏻\u{200d}🚒",
+    "🧑🏻\u{200d}🤝\u{200d}🧑🏻",
+    "🧑🏻\u{200d}🤝\u{200d}🧑🏼",
+    "🧑🏻\u{200d}🤝\u{200d}🧑🏽",
+    "🧑🏻\u{200d}🤝\u{200d}🧑🏾",
+    "🧑🏻\u{200d}🤝\u{200d}🧑🏿",
+    "🧑🏻\u{200d}🦯",
+    "🧑🏻\u{200d}🦰",
+    "🧑🏻\u{200d}🦱",
+    "🧑🏻\u{200d}🦲",
+    "🧑🏻\u{200d}🦳",
+    "🧑🏻\u{200d}🦼",
+    "🧑🏻\u{200d}🦽",
+    "🧑🏼\u{200d}⚕",
+    "🧑🏼\u{200d}⚖",
+    "🧑🏼\u{200d}✈",
+    "🧑🏼\u{200d}❤\u{200d}💋\u{200d}🧑🏻",
+    "🧑🏼\u{200d}❤\u{200d}💋\u{200d}🧑🏽",
+    "🧑🏼\u{200d}❤\u{200d}💋\u{200d}🧑🏾",
+    "🧑🏼\u{200d}❤\u{200d}💋\u{200d}🧑🏿",
+    "🧑🏼\u{200d}❤\u{200d}🧑🏻",
+    "🧑🏼\u{200d}❤\u{200d}🧑🏽",
+    "🧑🏼\u{200d}❤\u{200d}🧑🏾",
+    "🧑🏼\u{200d}❤\u{200d}🧑🏿",
+    "🧑🏼\u{200d}🌾",
+    "🧑🏼\u{200d}🍳",
+    "🧑🏼\u{200d}🍼",
+    "🧑🏼\u{200d}🎄",
+    "🧑🏼\u{200d}🎓",
+    "🧑🏼\u{200d}🎤",
+    "🧑🏼\u{200d}🎨",
+    "🧑🏼\u{200d}🏫",
+    "🧑🏼\u{200d}🏭",
+    "🧑🏼\u{200d}💻",
+    "🧑🏼\u{200d}💼",
+    "🧑🏼\u{200d}🔧",
+    "🧑🏼\u{200d}🔬",
+    "🧑🏼\u{200d}🚀",
+    "🧑🏼\u{200d}🚒",
+    "🧑🏼\u{200d}🤝\u{200d}🧑🏻",
+    "🧑🏼\u{200d}🤝\u{200d}🧑🏼",
+    "🧑🏼\u{200d}🤝\u{200d}🧑🏽",
+    "🧑🏼\u{200d}🤝\u{200d}🧑🏾",
+    "🧑🏼\u{200d}🤝\u{200d}🧑🏿",
+    "🧑🏼\u{200d}🦯",
+    "🧑🏼\u{200d}🦰",
+    "🧑🏼\u{200d}🦱",
+    "🧑🏼\u{200d}🦲",
+    "🧑🏼\u{200d}🦳",
+    "🧑🏼\u{200d}🦼",
+    "🧑🏼\u{200d}🦽",
+    "🧑🏽\u{200d}⚕",
+    "🧑🏽\u{200d}⚖",
+    "🧑🏽\u{200d}✈",
+    "🧑🏽\u{200d}❤\u{200d}💋\u{200d}🧑🏻",
+    "🧑🏽\u{200d}❤\u{200d}💋\u{200d}🧑🏼",
+    "🧑🏽\u{200d}❤\u{200d}💋\u{200d}🧑🏾",
+    "🧑🏽\u{200d}❤\u{200d}💋\u{200d}🧑🏿",
+    "🧑🏽\u{200d}❤\u{200d}🧑🏻",
+    "🧑🏽\u{200d}❤\u{200d}🧑🏼",
+    "🧑🏽\u{200d}❤\u{200d}🧑🏾",
+    "🧑🏽\u{200d}❤\u{200d}🧑🏿",
+    "🧑🏽\u{200d}🌾",
+    "🧑🏽\u{200d}🍳",
+    "🧑🏽\u{200d}🍼",
+    "🧑🏽\u{200d}🎄",
+    "🧑🏽\u{200d}🎓",
+    "🧑🏽\u{200d}🎤",
+    "🧑🏽\u{200d}🎨",
+    "🧑🏽\u{200d}🏫",
+    "🧑🏽\u{200d}🏭",
+    "🧑🏽\u{200d}💻",
+    "🧑🏽\u{200d}💼",
+    "🧑🏽\u{200d}🔧",
+    "🧑🏽\u{200d}🔬",
+    "🧑🏽\u{200d}🚀",
+    "🧑🏽\u{200d}🚒",
+    "🧑🏽\u{200d}🤝\u{200d}🧑🏻",
+    "🧑🏽\u{200d}🤝\u{200d}🧑🏼",
+    "🧑🏽\u{200d}🤝\u{200d}🧑🏽",
+    "🧑🏽\u{200d}🤝\u{200d}🧑🏾",
+    "🧑🏽\u{200d}🤝\u{200d}🧑🏿",
+    "🧑🏽\u{200d}🦯",
+    "🧑🏽\u{200d}🦰",
+    "🧑🏽\u{200d}🦱",
+    "🧑🏽\u{200d}🦲",
+    "🧑🏽\u{200d}🦳",
+    "🧑🏽\u{200d}🦼",
+    "🧑🏽\u{200d}🦽",
+    "🧑🏾\u{200d}⚕",
+    "🧑🏾\u{200d}⚖",
+    "🧑🏾\u{200d}✈",
+    "🧑🏾\u{200d}❤\u{200d}💋\u{200d}🧑🏻",
+    "🧑🏾\u{200d}❤\u{200d}💋\u{200d}🧑🏼",
+    "🧑🏾\u{200d}❤\u{200d}💋\u{200d}🧑🏽",
+    "🧑🏾\u{200d}❤\u{200d}💋\u{200d}🧑🏿",
+    "🧑🏾\u{200d}❤\u{200d}🧑🏻",
+    "🧑🏾\u{200d}❤\u{200d}🧑🏼",
+    "🧑🏾\u{200d}❤\u{200d}🧑🏽",
+    "🧑🏾\u{200d}❤\u{200d}🧑🏿",
+    "🧑🏾\u{200d}🌾",
+    "🧑🏾\u{200d}🍳",
+    "🧑🏾\u{200d}🍼",
+    "🧑🏾\u{200d}🎄",
+    "🧑🏾\u{200d}🎓",
+    "🧑🏾\u{200d}🎤",
+    "🧑🏾\u{200d}🎨",
+    "🧑🏾\u{200d}🏫",
+    "🧑🏾\u{200d}🏭",
+    "🧑🏾\u{200d}💻",
+    "🧑🏾\u{200d}💼",
+    "🧑🏾\u{200d}🔧",
+    "🧑🏾\u{200d}🔬",
+    "🧑🏾\u{200d}🚀",
+    "🧑🏾\u{200d}🚒",
+    "🧑🏾\u{200d}🤝\u{200d}🧑🏻",
+    "🧑🏾\u{200d}🤝\u{200d}🧑🏼",
+    "🧑🏾\u{200d}🤝\u{200d}🧑🏽",
+    "🧑🏾\u{200d}🤝\u{200d}🧑🏾",
+    "🧑🏾\u{200d}🤝\u{200d}🧑🏿",
+    "🧑🏾\u{200d}🦯",
+    "🧑🏾\u{200d}🦰",
+    "🧑🏾\u{200d}🦱",
+    "🧑🏾\u{200d}🦲",
+    "🧑🏾\u{200d}🦳",
+    "🧑🏾\u{200d}🦼",
+    "🧑🏾\u{200d}🦽",
+    "🧑🏿\u{200d}⚕",
+    "🧑🏿\u{200d}⚖",
+    "🧑🏿\u{200d}✈",
+    "🧑🏿\u{200d}❤\u{200d}💋\u{200d}🧑🏻",
+    "🧑🏿\u{200d}❤\u{200d}💋\u{200d}🧑🏼",
+    "🧑🏿\u{200d}❤\u{200d}💋\u{200d}🧑🏽",
+    "🧑🏿\u{200d}❤\u{200d}💋\u{200d}🧑🏾",
+    "🧑🏿\u{200d}❤\u{200d}🧑🏻",
+    "🧑🏿\u{200d}❤\u{200d}🧑🏼",
+    "🧑🏿\u{200d}❤\u{200d}🧑🏽",
+    "🧑🏿\u{200d}❤\u{200d}🧑🏾",
+    "🧑🏿\u{200d}🌾",
+    "🧑🏿\u{200d}🍳",
+    "🧑🏿\u{200d}🍼",
+    "🧑🏿\u{200d}🎄",
+    "🧑🏿\u{200d}🎓",
+    "🧑🏿\u{200d}🎤",
+    "🧑🏿\u{200d}🎨",
+    "🧑🏿\u{200d}🏫",
+    "🧑🏿\u{200d}🏭",
+    "🧑🏿\u{200d}💻",
+    "🧑🏿\u{200d}💼",
+    "🧑🏿\u{200d}🔧",
+    "🧑🏿\u{200d}🔬",
+    "🧑🏿\u{200d}🚀",
+    "🧑🏿\u{200d}🚒",
+    "🧑🏿\u{200d}🤝\u{200d}🧑🏻",
+    "🧑🏿\u{200d}🤝\u{200d}🧑🏼",
+    "🧑🏿\u{200d}🤝\u{200d}🧑🏽",
+    "🧑🏿\u{200d}🤝\u{200d}🧑🏾",
+    "🧑🏿\u{200d}🤝\u{200d}🧑🏿",
+    "🧑🏿\u{200d}🦯",
+    "🧑🏿\u{200d}🦰",
+    "🧑🏿\u{200d}🦱",
+    "🧑🏿\u{200d}🦲",
+    "🧑🏿\u{200d}🦳",
+    "🧑🏿\u{200d}🦼",
+    "🧑🏿\u{200d}🦽",
+    "🧔\u{200d}♀",
+    "🧔\u{200d}♂",
+    "🧔🏻\u{200d}♀",
+    "🧔🏻\u{200d}♂",
+    "🧔🏼\u{200d}♀",
+    "🧔🏼\u{200d}♂",
+    "🧔🏽\u{200d}♀",
+    "🧔🏽\u{200d}♂",
+    "🧔🏾\u{200d}♀",
+    "🧔🏾\u{200d}♂",
+    "🧔🏿\u{200d}♀",
+    "🧔🏿\u{200d}♂",
+    "🧖\u{200d}♀",
+    "🧖\u{200d}♂",
+    "🧖🏻\u{200d}♀",
+    "🧖🏻\u{200d}♂",
+    "🧖🏼\u{200d}♀",
+    "🧖🏼\u{200d}♂",
+    "🧖🏽\u{200d}♀",
+    "🧖🏽\u{200d}♂",
+    "🧖🏾\u{200d}♀",
+    "🧖🏾\u{200d}♂",
+    "🧖🏿\u{200d}♀",
+    "🧖🏿\u{200d}♂",
+    "🧗\u{200d}♀",
+    "🧗\u{200d}♂",
+    "🧗🏻\u{200d}♀",
+    "🧗🏻\u{200d}♂",
+    "🧗🏼\u{200d}♀",
+    "🧗🏼\u{200d}♂",
+    "🧗🏽\u{200d}♀",
+    "🧗🏽\u{200d}♂",
+    "🧗🏾\u{200d}♀",
+    "🧗🏾\u{200d}♂",
+    "🧗🏿\u{200d}♀",
+    "🧗🏿\u{200d}♂",
+    "🧘\u{200d}♀",
+    "🧘\u{200d}♂",
+    "🧘🏻\u{200d}♀",
+    "🧘🏻\u{200d}♂",
+    "🧘🏼\u{200d}♀",
+    "🧘🏼\u{200d}♂",
+    "🧘🏽\u{200d}♀",
+    "🧘🏽\u{200d}♂",
+    "🧘🏾\u{200d}♀",
+    "🧘🏾\u{200d}♂",
+    "🧘🏿\u{200d}♀",
+    "🧘🏿\u{200d}♂",
+    "🧙\u{200d}♀",
+    "🧙\u{200d}♂",
+    "🧙🏻\u{200d}♀",
+    "🧙🏻\u{200d}♂",
+    "🧙🏼\u{200d}♀",
+    "🧙🏼\u{200d}♂",
+    "🧙🏽\u{200d}♀",
+    "🧙🏽\u{200d}♂",
+    "🧙🏾\u{200d}♀",
+    "🧙🏾\u{200d}♂",
+    "🧙🏿\u{200d}♀",
+    "🧙🏿\u{200d}♂",
+    "🧚\u{200d}♀",
+    "🧚\u{200d}♂",
+    "🧚🏻\u{200d}♀",
+    "🧚🏻\u{200d}♂",
+    "🧚🏼\u{200d}♀",
+    "🧚🏼\u{200d}♂",
+    "🧚🏽\u{200d}♀",
+    "🧚🏽\u{200d}♂",
+    "🧚🏾\u{200d}♀",
+    "🧚🏾\u{200d}♂",
+    "🧚🏿\u{200d}♀",
+    "🧚🏿\u{200d}♂",
+    "🧛\u{200d}♀",
+    "🧛\u{200d}♂",
+    "🧛🏻\u{200d}♀",
+    "🧛🏻\u{200d}♂",
+    "🧛🏼\u{200d}♀",
+    "🧛🏼\u{200d}♂",
+    "🧛🏽\u{200d}♀",
+    "🧛🏽\u{200d}♂",
+    "🧛🏾\u{200d}♀",
+    "🧛🏾\u{200d}♂",
+    "🧛🏿\u{200d}♀",
+    "🧛🏿\u{200d}♂",
+    "🧜\u{200d}♀",
+    "🧜\u{200d}♂",
+    "🧜🏻\u{200d}♀",
+    "🧜🏻\u{200d}♂",
+    "🧜🏼\u{200d}♀",
+    "🧜🏼\u{200d}♂",
+    "🧜🏽\u{200d}♀",
+    "🧜🏽\u{200d}♂",
+    "🧜🏾\u{200d}♀",
+    "🧜🏾\u{200d}♂",
+    "🧜🏿\u{200d}♀",
+    "🧜🏿\u{200d}♂",
+    "🧝\u{200d}♀",
+    "🧝\u{200d}♂",
+    "🧝🏻\u{200d}♀",
+    "🧝🏻\u{200d}♂",
+    "🧝🏼\u{200d}♀",
+    "🧝🏼\u{200d}♂",
+    "🧝🏽\u{200d}♀",
+    "🧝🏽\u{200d}♂",
+    "🧝🏾\u{200d}♀",
+    "🧝🏾\u{200d}♂",
+    "🧝🏿\u{200d}♀",
+    "🧝🏿\u{200d}♂",
+    "🧞\u{200d}♀",
+    "🧞\u{200d}♂",
+    "🧟\u{200d}♀",
+    "🧟\u{200d}♂",
+    "🫱🏻\u{200d}🫲🏼",
+    "🫱🏻\u{200d}🫲🏽",
+    "🫱🏻\u{200d}🫲🏾",
+    "🫱🏻\u{200d}🫲🏿",
+    "🫱🏼\u{200d}🫲🏻",
+    "🫱🏼\u{200d}🫲🏽",
+    "🫱🏼\u{200d}🫲🏾",
+    "🫱🏼\u{200d}🫲🏿",
+    "🫱🏽\u{200d}🫲🏻",
+    "🫱🏽\u{200d}🫲🏼",
+    "🫱🏽\u{200d}🫲🏾",
+    "🫱🏽\u{200d}🫲🏿",
+    "🫱🏾\u{200d}🫲🏻",
+    "🫱🏾\u{200d}🫲🏼",
+    "🫱🏾\u{200d}🫲🏽",
+    "🫱🏾\u{200d}🫲🏿",
+    "🫱🏿\u{200d}🫲🏻",
+    "🫱🏿\u{200d}🫲🏼",
+    "🫱🏿\u{200d}🫲🏽",
+    "🫱🏿\u{200d}🫲🏾",
+];
@@ -0,0 +1,200 @@
+#[cfg(all(feature = "emoji", not(feature = "ascii-only")))]
+use crate::emoji_zwj_sequences::EMOJI_ZWJ_SEQUENCES;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+#[cfg(feature = "segmentation")]
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::uax29::{is_guaranteed_boundary, next_grapheme_len};
+use crate::{is_keycap_base, UnicodeCompat, WidthOptions};
+
+/// The combining enclosing keycap (U+20E3)
+const COMBINING_ENCLOSING_KEYCAP: char = '\u{20E3}';
+/// The zero width joiner (U+200D)
+#[cfg(all(feature = "emoji", not(feature = "ascii-only")))]
+const ZWJ: char = '\u{200D}';
+
+/// Computes the width of a single grapheme cluster.
+///
+/// Terminals render a grapheme cluster into at most two cells, so the
+/// widths of the individual characters are summed up and capped at two.
+/// The caller is responsible for segmenting the text into graphemes,
+/// passing multiple graphemes will produce incorrect results.
+#[inline]
+pub fn grapheme_width(grapheme: &str, unicode_compat: UnicodeCompat) -> usize {
+    grapheme_width_with(grapheme, WidthOptions::new(unicode_compat))
+}
+
+/// Computes the width of a single grapheme cluster like [`grapheme_width`]
+/// with additional configuration.
+pub fn grapheme_width_with(grapheme: &str, options: WidthOptions) -> usize {
+    if options.wide_bare_keycaps && is_bare_keycap(grapheme) {
+        return 2;
+    }
+    #[cfg(all(feature = "emoji", not(feature = "ascii-only")))]
+    if options.split_unknown_zwj_sequences
+        && grapheme.contains(ZWJ)
+        && !is_rgi_zwj_sequence(grapheme)
+    {
+        return grapheme
+            .split(ZWJ)
+            .map(|emoji| grapheme_width_with(emoji, options))
+            .sum();
+    }
+    let width = options.str_width(grapheme) + options.control_width(grapheme);
+    width.min(2)
+}
+
+/// Computes the total width of text that was already segmented into grapheme clusters
+/// (for example with the `unicode-segmentation` crate) by summing the [`grapheme_width`]
+/// of each cluster.
+///
+/// Each item must be exactly one grapheme cluster, the text is not segmented again.
+pub fn segmented_width<I, S>(clusters: I, unicode_compat: UnicodeCompat) -> usize
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    clusters
+        .into_iter()
+        .map(|cluster| grapheme_width(cluster.as_ref(), unicode_compat))
+        .sum()
+}
+
+/// Returns the byte range and [`grapheme_width`] of every grapheme cluster in `s`.
+///
+/// This is the information required to position glyphs during layout. If the
+/// `segmentation` feature is enabled the text is segmented with the `unicode-segmentation`
+/// crate, otherwise the built-in segmentation of [`next_grapheme_width`] is used.
+pub fn cluster_widths(s: &str, unicode_compat: UnicodeCompat) -> Vec<(Range<usize>, usize)> {
+    #[cfg(feature = "segmentation")]
+    let clusters = s.grapheme_indices(true);
+    #[cfg(not(feature = "segmentation"))]
+    let clusters = {
+        let mut start = 0;
+        core::iter::from_fn(move || {
+            let len = next_grapheme_len(&s[start..]);
+            let cluster = (start, &s[start..start + len]);
+            start += len;
+            (len != 0).then_some(cluster)
+        })
+    };
+    clusters
+        .map(|(start, grapheme)| {
+            let range = start..start + grapheme.len();
+            (range, grapheme_width(grapheme, unicode_compat))
+        })
+        .collect()
+}
+
+/// Returns whether `grapheme` is a keycap base directly followed by U+20E3
+/// (without VS16 in between).
+fn is_bare_keycap(grapheme: &str) -> bool {
+    let mut chars = grapheme.chars();
+    match (chars.next(), chars.next(), chars.next()) {
+        (Some(base), Some(COMBINING_ENCLOSING_KEYCAP), None) => is_keycap_base(base),
+        _ => false,
+    }
+}
+
+/// Returns whether `grapheme` is one of the emoji ZWJ sequences recommended
+/// for general interchange (RGI). VS16 is ignored so that sequences
+/// which omit VS16 are also recognized.
+#[cfg(all(feature = "emoji", not(feature = "ascii-only")))]
+fn is_rgi_zwj_sequence(grapheme: &str) -> bool {
+    let chars = || grapheme.chars().filter(|&c| c != '\u{FE0F}');
+    EMOJI_ZWJ_SEQUENCES
+        .binary_search_by(|sequence| sequence.chars().cmp(chars()))
+        .is_ok()
+}
+
+/// Computes the width and the length (in bytes) of the first grapheme cluster in `s`.
+/// Returns `None` if `s` is empty.
+///
+/// The grapheme cluster is determined with the extended grapheme cluster rules of
+/// [UAX #29](https://www.unicode.org/reports/tr29/) (see [`grapheme_indices`](crate::grapheme_indices)),
+/// so a base character is grouped with all following combining marks (including spacing
+/// marks like indic vowel signs), variation selectors and emoji modifiers.
+/// Emoji joined with a zero width joiner (ZWJ) and regional indicator pairs (flags)
+/// are also treated as a single grapheme.
+pub fn next_grapheme_width(s: &str, unicode_compat: UnicodeCompat) -> Option<(usize, usize)> {
+    let len = next_grapheme_len(s);
+    if len == 0 {
+        return None;
+    }
+    Some((grapheme_width(&s[..len], unicode_compat), len))
+}
+
+/// Computes the width and the length (in bytes) of the last grapheme cluster in `s`.
+/// Returns `None` if `s` is empty.
+///
+/// This is useful to determine how many columns to erase when the last grapheme
+/// is deleted (backspace). Trailing combining marks, variation selectors and
+/// other extending characters are grouped with their base like in [`next_grapheme_width`].
+pub fn last_grapheme_width(s: &str, unicode_compat: UnicodeCompat) -> Option<(usize, usize)> {
+    let mut start = last_grapheme_search_start(s);
+    let mut last = None;
+    loop {
+        let len = next_grapheme_len(&s[start..]);
+        if len == 0 {
+            break;
+        }
+        last = Some(&s[start..start + len]);
+        start += len;
+    }
+    last.map(|grapheme| (grapheme_width(grapheme, unicode_compat), grapheme.len()))
+}
+
+/// Returns a byte offset close to the end of `s` that is guaranteed to be
+/// a grapheme boundary, so that the last grapheme can be found by
+/// segmenting forward from there instead of from the start of `s`.
+fn last_grapheme_search_start(s: &str) -> usize {
+    let mut chars = s.char_indices().rev();
+    let (mut next_start, mut next) = match chars.next() {
+        Some(last) => last,
+        None => return 0,
+    };
+    for (start, prev) in chars {
+        if is_guaranteed_boundary(prev, next) {
+            return next_start;
+        }
+        next_start = start;
+        next = prev;
+    }
+    0
+}
+
+/// Iterator over the grapheme clusters of a string and their widths.
+///
+/// Yields each grapheme cluster as a `(slice, width)` pair, using the same
+/// segmentation as [`next_grapheme_width`].
+#[derive(Debug, Clone)]
+pub struct GraphemeMeasurer<'a> {
+    rem: &'a str,
+    unicode_compat: UnicodeCompat,
+}
+
+impl<'a> GraphemeMeasurer<'a> {
+    /// Creates an iterator over the grapheme clusters in `s`.
+    pub fn new(s: &'a str, unicode_compat: UnicodeCompat) -> GraphemeMeasurer<'a> {
+        GraphemeMeasurer {
+            rem: s,
+            unicode_compat,
+        }
+    }
+}
+
+impl<'a> Iterator for GraphemeMeasurer<'a> {
+    type Item = (&'a str, usize);
+
+    fn next(&mut self) -> Option<(&'a str, usize)> {
+        let len = next_grapheme_len(self.rem);
+        if len == 0 {
+            return None;
+        }
+        let (grapheme, rem) = self.rem.split_at(len);
+        self.rem = rem;
+        Some((grapheme, grapheme_width(grapheme, self.unicode_compat)))
+    }
+}
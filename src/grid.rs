@@ -0,0 +1,111 @@
+use crate::tracker::{Advance, VariationState};
+use crate::UnicodeCompat;
+
+/// Places characters into a grid with a fixed number of columns, the way a
+/// terminal emulator does when text is printed with automatic wrapping enabled.
+///
+/// A character that does not fit into the current row is moved to the start of
+/// the next row. If a wide character is written into the last column, the last
+/// cell of the row is left blank. Carriage returns (`\r`) move the cursor back to
+/// the start of the row, newlines (`\n`) to the start of the next row.
+/// Other control characters are ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GridWriter {
+    cols: usize,
+    row: usize,
+    /// The column of the cursor, equal to `cols` if the row is full
+    /// and the next character wraps
+    col: usize,
+    /// Position and width of the last character that occupies cells, so that
+    /// zero width characters and variation selectors can be attached to it.
+    last: Option<(usize, usize, usize)>,
+    variation: VariationState,
+}
+
+impl GridWriter {
+    /// Creates a grid that is `cols` columns wide (at least one)
+    /// with the cursor in the first cell.
+    pub fn new(cols: usize) -> GridWriter {
+        GridWriter {
+            cols: cols.max(1),
+            row: 0,
+            col: 0,
+            last: None,
+            variation: VariationState::new(),
+        }
+    }
+
+    /// The number of columns of the grid.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// The row of the cursor.
+    pub fn row(&self) -> usize {
+        self.row
+    }
+
+    /// The column of the cursor. This is equal to [`cols`](GridWriter::cols)
+    /// after the last column was written, until the next character wraps.
+    pub fn col(&self) -> usize {
+        self.col
+    }
+
+    /// Writes `c` into the grid and returns the `(row, col)` of the (first) cell it was placed in.
+    ///
+    /// Zero width characters (for example combining marks) do not occupy a cell,
+    /// they return the position of the preceding character. With [`UnicodeCompat::Unicode14`]
+    /// a variation selector changes the width of the preceding character if it fits into the row.
+    /// For control characters the new position of the cursor is returned.
+    pub fn write_char(&mut self, c: char, unicode_compat: UnicodeCompat) -> (usize, usize) {
+        match c {
+            '\r' => {
+                self.variation.reset();
+                self.col = 0;
+                self.last = None;
+                return (self.row, self.col);
+            }
+            '\n' => {
+                self.variation.reset();
+                self.row += 1;
+                self.col = 0;
+                self.last = None;
+                return (self.row, self.col);
+            }
+            _ if c.is_control() => {
+                self.variation.reset();
+                return (self.row, self.col);
+            }
+            _ => (),
+        }
+        let width = match self.variation.advance(c, unicode_compat) {
+            Advance::Width(width) => width,
+            Advance::Variation {
+                width: new_width, ..
+            } => {
+                if let Some((row, col, width)) = self.last.as_mut() {
+                    if *col + new_width <= self.cols {
+                        self.col = *col + new_width;
+                        *width = new_width;
+                    }
+                    return (*row, *col);
+                }
+                0
+            }
+        };
+        if width == 0 {
+            return match self.last {
+                Some((row, col, _)) => (row, col),
+                None => (self.row, self.col),
+            };
+        }
+        if self.col + width > self.cols && self.col != 0 {
+            self.row += 1;
+            self.col = 0;
+        }
+        let pos = (self.row, self.col);
+        self.last = Some((self.row, self.col, width));
+        self.col = (self.col + width).min(self.cols);
+        pos
+    }
+}
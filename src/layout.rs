@@ -0,0 +1,361 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::iter::Peekable;
+
+use crate::uax29::{next_grapheme_len, GraphemeExtender};
+use crate::{grapheme_width, str_width, UnicodeCompat};
+
+/// Alignment of text within a fixed number of columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Align {
+    /// Text starts at the first column, padding is added on the right.
+    #[default]
+    Left,
+    /// Text ends at the last column, padding is added on the left.
+    Right,
+    /// Padding is split evenly between both sides. If the padding
+    /// can not be split evenly the extra space is added on the right.
+    Center,
+    /// Text is placed at the start of the line in reading direction:
+    /// the left side for left-to-right and the right side for right-to-left text.
+    Start,
+    /// Text is placed at the end of the line in reading direction:
+    /// the right side for left-to-right and the left side for right-to-left text.
+    End,
+}
+
+impl Align {
+    /// Resolves [`Align::Start`] and [`Align::End`] to the visual side for `direction`.
+    fn resolve(self, direction: Direction) -> Align {
+        match (self, direction) {
+            (Align::Start, Direction::Ltr) | (Align::End, Direction::Rtl) => Align::Left,
+            (Align::End, Direction::Ltr) | (Align::Start, Direction::Rtl) => Align::Right,
+            (align, _) => align,
+        }
+    }
+}
+
+/// The reading direction of text, used to place padding for [`Align::Start`] and [`Align::End`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Direction {
+    /// Left-to-right text
+    #[default]
+    Ltr,
+    /// Right-to-left text (for example Arabic or Hebrew)
+    Rtl,
+}
+
+/// Pads `s` with spaces so that it occupies (at least) `width` columns.
+///
+/// Strings that are already `width` columns wide (or wider) are returned unchanged.
+/// The text is assumed to be left-to-right, see [`pad_to_width_directed`].
+pub fn pad_to_width(s: &str, width: usize, align: Align, unicode_compat: UnicodeCompat) -> String {
+    pad_to_width_cow(s, width, align, unicode_compat).into_owned()
+}
+
+/// Pads `s` like [`pad_to_width`] but places the padding for [`Align::Start`]
+/// and [`Align::End`] according to the reading `direction` of `s`.
+///
+/// This only decides on which side the fill is placed, the text itself is never
+/// reordered. The width computation does not depend on the direction.
+pub fn pad_to_width_directed(
+    s: &str,
+    width: usize,
+    align: Align,
+    direction: Direction,
+    unicode_compat: UnicodeCompat,
+) -> String {
+    pad_to_width_cow(s, width, align.resolve(direction), unicode_compat).into_owned()
+}
+
+/// Pads `s` with spaces like [`pad_to_width`] but only allocates if
+/// padding is actually required. The text is assumed to be left-to-right.
+pub fn pad_to_width_cow<'a>(
+    s: &'a str,
+    width: usize,
+    align: Align,
+    unicode_compat: UnicodeCompat,
+) -> Cow<'a, str> {
+    let padding = width.saturating_sub(str_width(s, unicode_compat));
+    if padding == 0 {
+        return Cow::Borrowed(s);
+    }
+    let (left, right) = split_padding(padding, align);
+    let mut res = String::with_capacity(s.len() + padding);
+    res.extend(core::iter::repeat(' ').take(left));
+    res.push_str(s);
+    res.extend(core::iter::repeat(' ').take(right));
+    Cow::Owned(res)
+}
+
+/// Pads every cell of a table row in place so that all cells are as wide
+/// as the widest cell, see [`pad_to_width`].
+///
+/// Cells that already have the maximum width are not modified.
+pub fn align_row(cells: &mut [String], align: Align, unicode_compat: UnicodeCompat) {
+    let width = cells
+        .iter()
+        .map(|cell| str_width(cell, unicode_compat))
+        .max()
+        .unwrap_or(0);
+    for cell in cells {
+        if let Cow::Owned(padded) = pad_to_width_cow(cell, width, align, unicode_compat) {
+            *cell = padded;
+        }
+    }
+}
+
+/// Writes `s` padded with `fill` to (at least) `width` columns into `w`
+/// like [`pad_to_width`] but without allocating an intermediate `String`.
+///
+/// `fill` is assumed to be a single width character, one fill character
+/// is written per missing column. The text is assumed to be left-to-right.
+pub fn pad_into<W: fmt::Write>(
+    w: &mut W,
+    s: &str,
+    width: usize,
+    align: Align,
+    fill: char,
+    unicode_compat: UnicodeCompat,
+) -> fmt::Result {
+    let padding = width.saturating_sub(str_width(s, unicode_compat));
+    let (left, right) = split_padding(padding, align);
+    for _ in 0..left {
+        w.write_char(fill)?;
+    }
+    w.write_str(s)?;
+    for _ in 0..right {
+        w.write_char(fill)?;
+    }
+    Ok(())
+}
+
+/// Splits `padding` columns into the fill placed left and right of left-to-right text.
+fn split_padding(padding: usize, align: Align) -> (usize, usize) {
+    match align.resolve(Direction::Ltr) {
+        Align::Left | Align::Start => (0, padding),
+        Align::Right | Align::End => (padding, 0),
+        Align::Center => (padding / 2, padding - padding / 2),
+    }
+}
+
+/// Returns the longest prefix of `s` that is at most `max_width` columns wide.
+///
+/// `s` is only truncated between grapheme clusters so a wide character that
+/// does not fit anymore is removed entirely, see [`take_width`].
+pub fn truncate_to_width(s: &str, max_width: usize, unicode_compat: UnicodeCompat) -> &str {
+    let (_, len) = take_width(s, max_width, unicode_compat);
+    &s[..len]
+}
+
+/// Splits `s` into the longest prefix that is at most `budget` columns wide
+/// (see [`truncate_to_width`]) and the remaining text.
+///
+/// The split is placed between grapheme clusters, so a wide character that does
+/// not fit is moved to the remainder entirely.
+pub fn split_fit(s: &str, budget: usize, unicode_compat: UnicodeCompat) -> (&str, &str) {
+    let (_, len) = take_width(s, budget, unicode_compat);
+    s.split_at(len)
+}
+
+/// Truncates `s` like [`truncate_to_width`] and additionally returns whether any
+/// content was dropped, so that the caller can append an ellipsis.
+///
+/// The width of the ellipsis is not taken into account: to fit the ellipsis
+/// into `max_width` columns, the caller has to reserve its width.
+pub fn truncate_to_width_marked(
+    s: &str,
+    max_width: usize,
+    unicode_compat: UnicodeCompat,
+) -> (&str, bool) {
+    let truncated = truncate_to_width(s, max_width, unicode_compat);
+    (truncated, truncated.len() != s.len())
+}
+
+/// Writes `s` into `w` truncated to at most `max_width` columns like
+/// [`truncate_to_width`], followed by `ellipsis` if any content was dropped.
+///
+/// The width of `ellipsis` is reserved so that the prefix and the ellipsis together
+/// are at most `max_width` columns wide. If `s` fits into `max_width` it is written
+/// unchanged without the ellipsis. If the ellipsis itself is wider than `max_width`
+/// nothing is written.
+pub fn truncate_ellipsis_into<W: fmt::Write>(
+    w: &mut W,
+    s: &str,
+    max_width: usize,
+    ellipsis: &str,
+    unicode_compat: UnicodeCompat,
+) -> fmt::Result {
+    let (_, len) = take_width(s, max_width, unicode_compat);
+    if len == s.len() {
+        return w.write_str(s);
+    }
+    let ellipsis_width = str_width(ellipsis, unicode_compat);
+    if ellipsis_width > max_width {
+        return Ok(());
+    }
+    w.write_str(truncate_to_width(
+        s,
+        max_width - ellipsis_width,
+        unicode_compat,
+    ))?;
+    w.write_str(ellipsis)
+}
+
+/// Computes the longest prefix of `s` that fits into `budget` columns.
+///
+/// Returns the number of columns occupied by the prefix and its length in bytes.
+/// The prefix always ends at a grapheme boundary, so the returned width
+/// may be smaller than `budget` if the next grapheme is a wide character.
+pub fn take_width(s: &str, budget: usize, unicode_compat: UnicodeCompat) -> (usize, usize) {
+    let mut width = 0;
+    let mut end = 0;
+    loop {
+        let len = next_grapheme_len(&s[end..]);
+        if len == 0 {
+            return (width, end);
+        }
+        let grapheme_width = grapheme_width(&s[end..end + len], unicode_compat);
+        if width + grapheme_width > budget {
+            return (width, end);
+        }
+        width += grapheme_width;
+        end += len;
+    }
+}
+
+/// Splits `s` into lines that are at most `max_width` columns wide.
+///
+/// Lines are only broken between grapheme clusters (not between words),
+/// just like a terminal wraps text that is too long. A grapheme that is wider
+/// than `max_width` is placed on a line of its own. Newlines (`\n` and `\r\n`)
+/// always start a new line and are not included in the returned lines.
+pub fn wrap(s: &str, max_width: usize, unicode_compat: UnicodeCompat) -> Vec<&str> {
+    wrap_iter(s, max_width, unicode_compat).collect()
+}
+
+/// Lazily splits `s` into lines like [`wrap`] without allocating.
+pub fn wrap_iter(
+    s: &str,
+    max_width: usize,
+    unicode_compat: UnicodeCompat,
+) -> impl Iterator<Item = &str> + '_ {
+    WrapIter {
+        rem: s,
+        max_width,
+        unicode_compat,
+    }
+}
+
+struct WrapIter<'a> {
+    rem: &'a str,
+    max_width: usize,
+    unicode_compat: UnicodeCompat,
+}
+
+impl<'a> Iterator for WrapIter<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        if self.rem.is_empty() {
+            return None;
+        }
+        let mut width = 0;
+        let mut end = 0;
+        loop {
+            let len = next_grapheme_len(&self.rem[end..]);
+            if len == 0 {
+                let line = self.rem;
+                self.rem = "";
+                return Some(line);
+            }
+            let grapheme = &self.rem[end..end + len];
+            if matches!(grapheme, "\n" | "\r\n") {
+                let line = &self.rem[..end];
+                self.rem = &self.rem[end + len..];
+                return Some(line);
+            }
+            let grapheme_width = grapheme_width(grapheme, self.unicode_compat);
+            if width + grapheme_width > self.max_width && end != 0 {
+                let (line, rem) = self.rem.split_at(end);
+                self.rem = rem;
+                return Some(line);
+            }
+            width += grapheme_width;
+            end += len;
+        }
+    }
+}
+
+/// Splits the characters yielded by `chars` into lines like [`wrap`].
+///
+/// This allows wrapping text that is not stored in a contiguous `&str`
+/// (for example a rope). Each line is returned as a `Vec<char>`.
+pub fn wrap_chars<I: Iterator<Item = char>>(
+    chars: I,
+    max_width: usize,
+    unicode_compat: UnicodeCompat,
+) -> impl Iterator<Item = Vec<char>> {
+    WrapChars {
+        chars: chars.peekable(),
+        grapheme: String::new(),
+        max_width,
+        unicode_compat,
+    }
+}
+
+struct WrapChars<I: Iterator<Item = char>> {
+    chars: Peekable<I>,
+    /// The next grapheme, that did not fit into the previous line
+    grapheme: String,
+    max_width: usize,
+    unicode_compat: UnicodeCompat,
+}
+
+impl<I: Iterator<Item = char>> WrapChars<I> {
+    /// Reads the next grapheme from `chars` into `grapheme` unless there
+    /// is already a pending grapheme. Returns `false` at the end of the input.
+    fn fill_grapheme(&mut self) -> bool {
+        if !self.grapheme.is_empty() {
+            return true;
+        }
+        let mut extender = match self.chars.next() {
+            Some(first) => {
+                self.grapheme.push(first);
+                GraphemeExtender::new(first)
+            }
+            None => return false,
+        };
+        while let Some(c) = self.chars.next_if(|&c| extender.extends(c)) {
+            self.grapheme.push(c);
+        }
+        true
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for WrapChars<I> {
+    type Item = Vec<char>;
+
+    fn next(&mut self) -> Option<Vec<char>> {
+        if !self.fill_grapheme() {
+            return None;
+        }
+        let mut line = Vec::new();
+        let mut width = 0;
+        while self.fill_grapheme() {
+            if matches!(&*self.grapheme, "\n" | "\r\n") {
+                self.grapheme.clear();
+                break;
+            }
+            let grapheme_width = grapheme_width(&self.grapheme, self.unicode_compat);
+            if width + grapheme_width > self.max_width && !line.is_empty() {
+                break;
+            }
+            width += grapheme_width;
+            line.extend(self.grapheme.drain(..));
+        }
+        Some(line)
+    }
+}
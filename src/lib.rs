@@ -1,15 +1,94 @@
 #![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))]
+#![cfg_attr(all(not(feature = "std"), not(test)), no_std)]
 
+extern crate alloc;
+
+use core::ops::Range;
+
+#[cfg(feature = "emoji")]
 use crate::emoji_variations::EMOJI_VARIATIONS;
 
+#[allow(warnings)]
+mod ambiguous;
+mod ansi;
+mod bytes;
+#[cfg(feature = "std")]
+mod cache;
+mod cjk;
+mod display;
+#[allow(warnings)]
+mod emoji_presentation;
 #[allow(warnings)]
 mod emoji_variations;
 #[allow(warnings)]
-mod table;
+mod emoji_zwj_sequences;
+mod grapheme;
+#[allow(warnings)]
+mod grapheme_break;
+mod grid;
+mod layout;
+#[cfg(feature = "std")]
+mod lines;
+mod options;
+#[cfg(feature = "std")]
+mod os_str;
+mod overrides;
+#[cfg(feature = "emoji")]
+mod presentation;
+mod profile;
+#[cfg(feature = "segmentation")]
+mod segmentation;
+mod single_cache;
+mod strict;
 #[cfg(test)]
 mod test;
+#[cfg(all(test, feature = "ascii-only"))]
+mod test_ascii_only;
+mod tracker;
+mod uax29;
+#[allow(warnings)]
+mod unassigned;
+mod wcwidth;
+mod width;
 
-pub use table::UNICODE_VERSION;
+pub use ansi::str_width_ansi;
+pub use bytes::{bytes_width, bytes_width_with};
+#[cfg(feature = "std")]
+pub use cache::WidthCache;
+pub use cjk::{str_width_cjk, str_width_contextual};
+pub use display::display_width;
+pub use grapheme::{
+    cluster_widths, grapheme_width, grapheme_width_with, last_grapheme_width, next_grapheme_width,
+    segmented_width, GraphemeMeasurer,
+};
+#[cfg(not(feature = "ascii-only"))]
+use grapheme_width_tables::lookup_width;
+pub use grapheme_width_tables::UNICODE_VERSION;
+pub use grid::GridWriter;
+pub use layout::{
+    align_row, pad_into, pad_to_width, pad_to_width_cow, pad_to_width_directed, split_fit,
+    take_width, truncate_ellipsis_into, truncate_to_width, truncate_to_width_marked, wrap,
+    wrap_chars, wrap_iter, Align, Direction,
+};
+#[cfg(feature = "std")]
+pub use lines::lines_widths;
+pub use options::{caret_notation, ControlPolicy, WidthOptions};
+#[cfg(feature = "std")]
+pub use os_str::{os_str_width, os_str_width_with};
+pub use overrides::{InvalidOverride, WidthOverrides};
+#[cfg(feature = "emoji")]
+pub use presentation::{resolve_presentation, Presentation};
+#[cfg(feature = "std")]
+pub use profile::detect_unicode_compat;
+pub use profile::TerminalProfile;
+#[cfg(feature = "segmentation")]
+pub use segmentation::str_width_segmented;
+pub use single_cache::SingleWidthCache;
+pub use strict::{str_width_with_fallback, try_char_width, try_codepoint_width, WidthError};
+pub use tracker::ColumnTracker;
+pub use uax29::grapheme_indices;
+pub use wcwidth::{wcswidth, wcwidth};
+pub use width::{InvalidWidth, Width};
 
 /// Controls backwards compatability with older Unicode version.
 /// The core width lookup tables are always generated from the newest
@@ -31,7 +110,8 @@ pub enum UnicodeCompat {
     /// Compatiable with Unicode Versions 9 to 14.
     ///
     /// As of early 2023 this setting is correct for the following emulators (non exhaustive):
-    /// `wezterm` (configurable), `alacritty`, `gnome console`, `kde konsole` and `iterm`.
+    /// `wezterm` (configurable), `alacritty`, `gnome console`, `kde konsole`, `iterm`,
+    /// `xterm`, `mintty` and `PuTTY`.
     ///
     /// Variant selectors can not change the presentation/width of emojis
     #[default]
@@ -43,73 +123,399 @@ pub enum UnicodeCompat {
     ///
     /// With this compatability level emoji variant selectors
     /// can change the presentation of some emojis
-    /// between text presentation (width 1) and emoji presentation (width 2)
+    /// between text presentation (width 1) and emoji presentation (width 2).
+    /// This includes the ASCII keycap bases `0`-`9`, `#` and `*`, so `1` followed by
+    /// VS16 (and the keycap emoji `1` VS16 U+20E3) is double width.
     Unicode14,
 }
 
+impl UnicodeCompat {
+    /// Returns a stable numeric identifier for this compatability level,
+    /// for example to pass it across an FFI boundary or to serialize it.
+    ///
+    /// [`UnicodeCompat::Unicode9`] is `0` and [`UnicodeCompat::Unicode14`] is `1`.
+    /// Levels added in the future receive increasing numbers.
+    pub const fn as_level(self) -> u32 {
+        match self {
+            UnicodeCompat::Unicode9 => 0,
+            UnicodeCompat::Unicode14 => 1,
+        }
+    }
+
+    /// Converts a numeric identifier produced by [`as_level`](UnicodeCompat::as_level)
+    /// back to a compatability level. Returns `None` for unknown levels.
+    pub const fn from_level(level: u32) -> Option<UnicodeCompat> {
+        match level {
+            0 => Some(UnicodeCompat::Unicode9),
+            1 => Some(UnicodeCompat::Unicode14),
+            _ => None,
+        }
+    }
+}
+
 /// Computes the width of a string
+///
+/// The characters are measured individually (apart from variation selectors), so the
+/// zero width joiner (U+200D) is always zero width regardless of the surrounding
+/// characters. An emoji ZWJ sequence is measured as the sum of the joined emoji,
+/// use [`grapheme_width`] to measure it as a single glyph.
+///
+/// With [`UnicodeCompat::Unicode14`] the emoji variation selector (VS16) makes the
+/// preceding character double width if its presentation can be changed. Apart from
+/// emoji this applies to the keycap bases `0`-`9`, `#` and `*`: `"1\u{FE0F}"` and the
+/// keycap emoji `"1\u{FE0F}\u{20E3}"` are double width while `"1"` is single width.
 #[inline]
 pub fn str_width(s: &str, unicode_compact: UnicodeCompat) -> usize {
-    let mut chars = s.chars();
     match unicode_compact {
-        UnicodeCompat::Unicode9 => chars.map(char_width_unicode9).sum(),
-        UnicodeCompat::Unicode14 => {
-            let mut res = 0;
-            while let Some(c) = chars.next() {
-                println!("{c:?}");
-                if c.is_ascii() {
-                    res += (!(c as u8).is_ascii_control()) as usize;
+        UnicodeCompat::Unicode9 => {
+            let (width, len) = ascii_prefix_width(s.as_bytes());
+            width + s[len..].chars().map(char_width_unicode9).sum::<usize>()
+        }
+        UnicodeCompat::Unicode14 => str_width_unicode14(s),
+    }
+}
+
+/// Computes the width of a string for [`UnicodeCompat::Unicode14`].
+///
+/// The string is scanned by byte offset so that variation selectors
+/// can be recognized (and skipped) directly in the UTF-8 encoding.
+fn str_width_unicode14(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let (mut res, mut i) = ascii_prefix_width(bytes);
+    // a keycap base may be followed by VS16 or U+20E3
+    if i != bytes.len() && i != 0 && is_keycap_base(bytes[i - 1] as char) {
+        i -= 1;
+        res -= 1;
+    }
+    while let Some(&first) = bytes.get(i) {
+        if first.is_ascii() && !is_keycap_base(first as char) {
+            res += (!first.is_ascii_control()) as usize;
+            i += 1;
+            continue;
+        }
+        let (c, len) = decode_utf8(bytes, i);
+        i += len;
+        // For unicode 14 respect emoji-variations.txt
+        // If there is no explicit variant select then the default width algorithm always
+        // returns the width for the default presentation so no need to specical case
+        if is_emoji_variation(c) {
+            match bytes[i..] {
+                // text variant select U-FE0E as bytes
+                [0xef, 0xb8, 0x8e, ..] => {
+                    debug_check_variation_selector(&s[i..], Some('\u{FE0E}'));
+                    res += 1;
+                    i += 3;
                     continue;
                 }
-                // For unicode 14 respect emoji-variations.txt
-                // If there is no explicit variant select then the default width algorithm always
-                // returns the width for the default presentation so no need to specical case
-                if EMOJI_VARIATIONS.contains_char(c) {
-                    match chars.as_str().as_bytes() {
-                        // text variant select U-FE0E as bytes
-                        [0xef, 0xb8, 0x8e, ..] => {
-                            chars = chars.as_str()[3..].chars();
-                            res += 1;
-                            continue;
-                        }
-                        // emoji variant select U-FE0F as bytes
-                        [0xef, 0xb8, 0x8f, ..] => {
-                            chars = chars.as_str()[3..].chars();
-                            res += 2;
-                            continue;
-                        }
-                        _ => (),
-                    }
+                // emoji variant select U-FE0F as bytes
+                [0xef, 0xb8, 0x8f, ..] => {
+                    debug_check_variation_selector(&s[i..], Some('\u{FE0F}'));
+                    res += 2;
+                    i += 3;
+                    continue;
                 }
+                _ => debug_check_variation_selector(&s[i..], None),
+            }
+        }
+        res += lookup_width(c) as usize;
+    }
+    res
+}
 
-                let width = lookup_width(c) as usize;
-                res += width;
+/// Computes the width of the longest ASCII prefix of `bytes` without decoding UTF-8:
+/// every printable ASCII character is single width and control characters
+/// are zero width. Returns the width and the length of the prefix.
+#[inline]
+fn ascii_prefix_width(bytes: &[u8]) -> (usize, usize) {
+    let mut controls = 0;
+    let mut len = 0;
+    // check eight bytes at once for non-ASCII bytes
+    for chunk in bytes.chunks_exact(8) {
+        if chunk.iter().any(|byte| !byte.is_ascii()) {
+            break;
+        }
+        controls += chunk.iter().filter(|byte| byte.is_ascii_control()).count();
+        len += 8;
+    }
+    for byte in &bytes[len..] {
+        if !byte.is_ascii() {
+            break;
+        }
+        controls += byte.is_ascii_control() as usize;
+        len += 1;
+    }
+    (len - controls, len)
+}
+
+/// Decodes the character that starts at byte `i` of a `&str`
+/// and returns it together with its length in bytes.
+#[inline]
+fn decode_utf8(bytes: &[u8], i: usize) -> (char, usize) {
+    let continuation = |offset: usize| (bytes[i + offset] & 0x3f) as u32;
+    let first = bytes[i] as u32;
+    let (codepoint, len) = match first {
+        0x00..=0x7f => (first, 1),
+        0xc0..=0xdf => ((first & 0x1f) << 6 | continuation(1), 2),
+        0xe0..=0xef => (
+            (first & 0x0f) << 12 | continuation(1) << 6 | continuation(2),
+            3,
+        ),
+        _ => (
+            (first & 0x07) << 18 | continuation(1) << 12 | continuation(2) << 6 | continuation(3),
+            4,
+        ),
+    };
+    let c = char::from_u32(codepoint).unwrap_or(char::REPLACEMENT_CHARACTER);
+    (c, len)
+}
+
+/// Computes the width of the characters yielded by `chars` in reverse order,
+/// for example `s.chars().rev()`. The result is the same as [`str_width`] for the
+/// string in forward order.
+///
+/// Variation selectors appear before their base character in reverse order,
+/// so they are remembered until the next character is yielded.
+pub fn rev_iter_width<I: Iterator<Item = char>>(chars: I, unicode_compat: UnicodeCompat) -> usize {
+    match unicode_compat {
+        UnicodeCompat::Unicode9 => chars.map(char_width_unicode9).sum(),
+        UnicodeCompat::Unicode14 => {
+            let mut res = 0;
+            let mut next_char = None;
+            for c in chars {
+                let mut buf = [0; 4];
+                let rem = match next_char {
+                    Some(next_char @ ('\u{FE0E}' | '\u{FE0F}')) => next_char.encode_utf8(&mut buf),
+                    _ => "",
+                };
+                res += char_width_unicode14(c, rem);
+                next_char = Some(c);
             }
             res
         }
     }
 }
 
+/// Computes the width of a string like [`str_width`] with additional configuration.
+pub fn str_width_with(s: &str, options: WidthOptions) -> usize {
+    let s = match s.find('\n') {
+        Some(line_end) if options.stop_at_newline => &s[..line_end],
+        _ => s,
+    };
+    options.str_width(s) + options.control_width(s)
+}
+
+/// Computes the width of a string like [`str_width`] but returns `None`
+/// if the width does not fit into a `usize`.
+///
+/// Every character is at most two columns wide and at least one byte long,
+/// so the width of a string is at most twice its length in bytes. Because a
+/// `str` can be at most `isize::MAX` bytes long, the width of a single string can
+/// not actually overflow. However, widths that are accumulated across
+/// many strings can, see [`checked_str_width_after`].
+pub fn checked_str_width(s: &str, unicode_compat: UnicodeCompat) -> Option<usize> {
+    checked_str_width_after(0, s, unicode_compat)
+}
+
+/// Adds the width of `s` to `width` (for example the width of the preceding text)
+/// and returns `None` if the result does not fit into a `usize`.
+pub fn checked_str_width_after(
+    width: usize,
+    s: &str,
+    unicode_compat: UnicodeCompat,
+) -> Option<usize> {
+    char_width_indices(s, unicode_compat).try_fold(width, |width, (_, _, char_width)| {
+        width.checked_add(char_width)
+    })
+}
+
+/// Computes how much the width changes when `old` is replaced by `new`.
+///
+/// Returns `str_width(new) - str_width(old)`: the result is negative
+/// if `new` is narrower than `old`.
+pub fn width_delta(old: &str, new: &str, unicode_compat: UnicodeCompat) -> isize {
+    str_width(new, unicode_compat) as isize - str_width(old, unicode_compat) as isize
+}
+
+/// Computes the width of `s` without its trailing whitespace, which is the
+/// column after the last visible ("ink") character of a line.
+///
+/// All unicode whitespace (like tabs or the ideographic space U+3000) is trimmed
+/// except for the no-break spaces U+00A0, U+2007 and U+202F: these are used to
+/// deliberately keep text together or aligned, so they are treated as content.
+/// Leading and internal whitespace is still counted. A string that only contains
+/// trimmable whitespace has width 0.
+pub fn trimmed_width(s: &str, unicode_compat: UnicodeCompat) -> usize {
+    str_width(s.trim_end_matches(is_trimmable_whitespace), unicode_compat)
+}
+
+/// Returns whether `c` is whitespace (`White_Space`) that is removed by [`trimmed_width`].
+/// This is [`char::is_whitespace`] without the no-break spaces.
+fn is_trimmable_whitespace(c: char) -> bool {
+    matches!(
+        c,
+        '\t'..='\r'
+            | ' '
+            | '\u{85}'
+            | '\u{1680}'
+            | '\u{2000}'..='\u{2006}'
+            | '\u{2008}'..='\u{200A}'
+            | '\u{2028}'
+            | '\u{2029}'
+            | '\u{205F}'
+            | '\u{3000}'
+    )
+}
+
+/// Returns whether `a` and `b` have the same display width.
+///
+/// Only the shorter string (in bytes) is measured completely. Measuring
+/// the longer string stops as soon as it is wider than the shorter one.
+pub fn same_width(a: &str, b: &str, unicode_compat: UnicodeCompat) -> bool {
+    let (short, long) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+    let expected = str_width(short, unicode_compat);
+    let mut width = 0;
+    for (_, _, char_width) in char_width_indices(long, unicode_compat) {
+        width += char_width;
+        if width > expected {
+            return false;
+        }
+    }
+    width == expected
+}
+
+/// Computes the width of the text in `s` before the byte offset `byte`,
+/// for example the column of a cursor placed at `byte`.
+///
+/// Only characters that end before `byte` are taken into account.
+/// If `byte` is placed between an emoji and a following variation selector,
+/// the width change caused by the variation selector is not counted yet.
+///
+/// `byte` must lie on a char boundary, this is checked in debug builds only.
+/// In release builds an offset inside a character is rounded down.
+pub fn width_until_byte(s: &str, byte: usize, unicode_compat: UnicodeCompat) -> usize {
+    debug_assert!(
+        s.is_char_boundary(byte),
+        "byte offset {byte} is not a char boundary"
+    );
+    let mut end = byte.min(s.len());
+    while !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    str_width(&s[..end], unicode_compat)
+}
+
+/// Computes the width of the text in `s` before the first character for which
+/// `stop` returns `true`. The stop character itself is not counted.
+/// If no character matches, the width of the whole string is returned.
+///
+/// This generalizes [`WidthOptions::stop_at_newline`] to arbitrary delimiters.
+/// `stop` receives each raw character, including combining marks and variation selectors.
+pub fn width_until<F: Fn(char) -> bool>(s: &str, stop: F, unicode_compat: UnicodeCompat) -> usize {
+    let end = s.find(stop).unwrap_or(s.len());
+    str_width(&s[..end], unicode_compat)
+}
+
+/// Computes the columns occupied by the text in the byte range `range` of `s`,
+/// for example to highlight a selection.
+///
+/// The start of the returned range is the width of the text before `range.start`
+/// and the end is the width of the text before `range.end`. A boundary that falls
+/// between an emoji and its variation selector (VS15/VS16) is snapped so that the
+/// emoji and the variation selector are either both selected or both unselected.
+/// Boundaries inside a character are rounded outwards. An empty `range` always
+/// produces an empty column range.
+pub fn column_range(s: &str, range: Range<usize>, unicode_compat: UnicodeCompat) -> Range<usize> {
+    let mut start = range.start.min(s.len());
+    while !s.is_char_boundary(start) {
+        start -= 1;
+    }
+    if starts_with_variation_selector(&s[start..]) {
+        if let Some(base) = s[..start].chars().next_back() {
+            start -= base.len_utf8();
+        }
+    }
+    let start_column = str_width(&s[..start], unicode_compat);
+    if range.end <= range.start {
+        return start_column..start_column;
+    }
+    let mut end = range.end.clamp(start, s.len());
+    while !s.is_char_boundary(end) {
+        end += 1;
+    }
+    if starts_with_variation_selector(&s[end..]) {
+        end += 3;
+    }
+    start_column..start_column + str_width(&s[start..end], unicode_compat)
+}
+
+/// Returns whether `s` starts with a text (VS15) or emoji (VS16) variation selector.
 #[inline]
-fn lookup_width(c: char) -> u8 {
-    use table::*;
-    let cp = c as usize;
+fn starts_with_variation_selector(s: &str) -> bool {
+    // U+FE0E and U+FE0F as bytes
+    let res = matches!(s.as_bytes(), [0xef, 0xb8, 0x8e | 0x8f, ..]);
+    debug_assert_eq!(res, s.starts_with(['\u{FE0E}', '\u{FE0F}']));
+    res
+}
 
-    let t1_offset = TABLE_0[cp >> 13 & 0xFF];
+/// Cross-checks the hand-written UTF-8 byte patterns of VS15 and VS16 in debug builds.
+///
+/// `selector` is the variation selector that was matched at the start of `rem`
+/// (or `None` if no selector was matched). This must agree with decoding
+/// the first `char` of `rem`. Release builds only use the byte patterns.
+#[inline]
+fn debug_check_variation_selector(rem: &str, selector: Option<char>) {
+    debug_assert_eq!(
+        rem.chars()
+            .next()
+            .filter(|&c| matches!(c, '\u{FE0E}' | '\u{FE0F}')),
+        selector,
+        "variation selector byte pattern does not match the decoded char"
+    );
+}
 
-    // Each sub-table in TABLES_1 is 7 bits, and each stored entry is a byte,
-    // so each sub-table is 128 bytes in size.
-    // (Sub-tables are selected using the computed offset from the previous table.)
-    let t2_offset = TABLE_1[128 * usize::from(t1_offset) + (cp >> 6 & 0x7F)];
+/// Returns whether `c` is one of the ASCII characters that can form a keycap
+/// emoji sequence (`c` VS16 U+20E3). These are the only ASCII characters
+/// whose presentation can be changed with VS15/VS16.
+#[inline]
+fn is_keycap_base(c: char) -> bool {
+    matches!(c, '0'..='9' | '#' | '*')
+}
+
+/// Returns whether the presentation of `c` can be changed with VS15/VS16.
+/// Always returns `false` if the `emoji` feature is disabled.
+#[inline]
+fn is_emoji_variation(c: char) -> bool {
+    #[cfg(feature = "emoji")]
+    {
+        EMOJI_VARIATIONS.contains_char(c)
+    }
+    #[cfg(not(feature = "emoji"))]
+    {
+        let _ = c;
+        false
+    }
+}
 
-    // Each sub-table in TABLES_2 is 6 bits, but each stored entry is 2 bits.
-    // This is accomplished by packing four stored entries into one byte.
-    // So each sub-table is 2**(6-2) == 16 bytes in size.
-    // Since this is the last table, each entry represents an encoded width.
-    let packed_widths = TABLE_2[16 * usize::from(t2_offset) + (cp >> 2 & 0xF)];
+/// Width reported for all non-ASCII characters when the `ascii-only` feature is enabled.
+#[cfg(feature = "ascii-only")]
+const ASCII_ONLY_FALLBACK_WIDTH: u8 = 1;
 
-    // Extract the packed width
-    packed_widths >> (2 * (cp & 0b11)) & 0b11
+#[inline]
+#[cfg(feature = "ascii-only")]
+fn lookup_width(_c: char) -> u8 {
+    ASCII_ONLY_FALLBACK_WIDTH
+}
+
+/// Returns the raw width (0, 1 or 2) of `c` stored in the width lookup table.
+///
+/// Unlike [`char_width_unicode9`] ASCII characters are not special cased and
+/// emoji variation selectors are never taken into account. This is intended for
+/// tools that build their own width logic on top of the tables of this crate.
+/// With the `ascii-only` feature the lookup tables are not compiled and
+/// every character is reported as single width.
+#[inline]
+pub fn packed_width(c: char) -> u8 {
+    lookup_width(c)
 }
 
 /// Calculates the width of a single character. This never takes text represeentation
@@ -123,26 +529,111 @@ pub fn char_width_unicode9(c: char) -> usize {
     lookup_width(c) as usize
 }
 
+/// Calculates the width of a single character `c` that is followed by `rem` like
+/// [`char_width_unicode9`] but honors a following text variation selector (VS15).
+///
+/// Some terminals do not implement the unicode 14 width changes for emoji
+/// presentation (VS16) but still render emoji that default to emoji presentation
+/// (like ⌚) as a single width text glyph when they are followed by VS15.
+/// For these terminals VS15 reduces the width of an emoji variation character to one,
+/// while VS16 is ignored. Without the `emoji` feature this is equivalent to
+/// [`char_width_unicode9`].
+#[inline]
+pub fn char_width_unicode9_vs(c: char, rem: &str) -> usize {
+    if is_emoji_variation(c) {
+        // text variant select U-FE0E as bytes
+        if let [0xef, 0xb8, 0x8e, ..] = rem.as_bytes() {
+            debug_check_variation_selector(rem, Some('\u{FE0E}'));
+            return 1;
+        }
+    }
+    char_width_unicode9(c)
+}
+
 /// Calculates the width of a single character that is followed by a text
 /// representation character. This never takes text represeentation into account
 /// and therefore implies `UnicodeCompat::Unicode14`. For non-emoji
 /// characters this is equivalent to [`char_width_unicode9`].
 #[inline]
 pub fn char_width_unicode14(c: char, rem: &str) -> usize {
-    if c.is_ascii() {
+    if c.is_ascii() && !is_keycap_base(c) {
         return (!(c as u8).is_ascii_control()) as usize;
     }
     // For unicode 14 respect emoji-variations.txt
     // If there is no explicit variant select then the default width algorithm always
     // returns the width for the default presentation so no need to specical case
-    if EMOJI_VARIATIONS.contains_char(c) {
+    if is_emoji_variation(c) {
         match rem.as_bytes() {
             // text variant select U-FE0E as bytes
-            [0xef, 0xb8, 0x8e, ..] => return 1,
+            [0xef, 0xb8, 0x8e, ..] => {
+                debug_check_variation_selector(rem, Some('\u{FE0E}'));
+                return 1;
+            }
             // emoji variant select U-FE0F as bytes
-            [0xef, 0xb8, 0x8f, ..] => return 2,
-            _ => (),
+            [0xef, 0xb8, 0x8f, ..] => {
+                debug_check_variation_selector(rem, Some('\u{FE0F}'));
+                return 2;
+            }
+            _ => debug_check_variation_selector(rem, None),
         }
     }
     lookup_width(c) as usize
 }
+
+/// Calculates the width of a single character `c` that is followed by `rem`
+/// for the given compatability level.
+#[inline]
+fn char_width(c: char, rem: &str, unicode_compat: UnicodeCompat) -> usize {
+    match unicode_compat {
+        UnicodeCompat::Unicode9 => char_width_unicode9(c),
+        UnicodeCompat::Unicode14 => char_width_unicode14(c, rem),
+    }
+}
+
+/// Calculates the width of a raw codepoint (for example received over FFI) that is not
+/// followed by a variation selector.
+///
+/// Returns `None` for surrogates (U+D800 to U+DFFF) and values outside of the unicode
+/// codespace (above U+10FFFF), which are not valid characters. Unlike
+/// [`try_codepoint_width`] control characters and unassigned codepoints are not rejected.
+pub fn codepoint_width(codepoint: u32, unicode_compat: UnicodeCompat) -> Option<usize> {
+    char::from_u32(codepoint).map(|c| char_width(c, "", unicode_compat))
+}
+
+/// Returns an iterator over the characters of `s` that yields the byte offset,
+/// the character itself and its width.
+///
+/// The width added by an emoji variation selector (VS16) is attributed to the
+/// preceding emoji. The variation selector itself is yielded with width zero.
+/// Therefore the widths always sum up to [`str_width`].
+pub fn char_width_indices(
+    s: &str,
+    unicode_compat: UnicodeCompat,
+) -> impl Iterator<Item = (usize, char, usize)> + '_ {
+    s.char_indices().map(move |(i, c)| {
+        let rem = &s[i + c.len_utf8()..];
+        (i, c, char_width(c, rem, unicode_compat))
+    })
+}
+
+/// Returns an iterator over all characters (excluding surrogates) that are `width` columns
+/// wide when they are not followed by a variation selector.
+///
+/// This scans the entire unicode codespace and is intended for building test corpora.
+pub fn codepoints_with_width(
+    width: u8,
+    unicode_compat: UnicodeCompat,
+) -> impl Iterator<Item = char> {
+    ('\0'..=char::MAX).filter(move |&c| char_width(c, "", unicode_compat) == usize::from(width))
+}
+
+/// Returns an iterator over all characters that have a text and an emoji presentation
+/// that can be selected with a variation selector (VS15 and VS16), as listed in
+/// `emoji-variation-sequences.txt`.
+///
+/// The lookup table only supports membership tests, so this scans the entire unicode
+/// codespace (about 1.1 million lookups). Collect the result if it is needed repeatedly.
+/// If the `emoji` feature is disabled the iterator is empty.
+pub fn emoji_variation_codepoints() -> impl Iterator<Item = char> {
+    ('\0'..=char::MAX).filter(|&c| is_emoji_variation(c))
+}
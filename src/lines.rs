@@ -0,0 +1,17 @@
+use std::io::{self, BufRead};
+
+use crate::{str_width, UnicodeCompat};
+
+/// Returns an iterator over the widths of the lines read from `reader`.
+///
+/// Lines are read one at a time (see [`BufRead::lines`]) so the entire input
+/// is never loaded into memory. The line endings (`\n` or `\r\n`) are not
+/// included in the width. Reading a line that is not valid UTF-8 yields an error.
+pub fn lines_widths<R: BufRead>(
+    reader: R,
+    unicode_compat: UnicodeCompat,
+) -> impl Iterator<Item = io::Result<usize>> {
+    reader
+        .lines()
+        .map(move |line| line.map(|line| str_width(&line, unicode_compat)))
+}
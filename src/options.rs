@@ -0,0 +1,269 @@
+use crate::cjk::is_ambiguous;
+use crate::{char_width_indices, UnicodeCompat};
+
+/// Configuration for the `*_with` family of width functions.
+///
+/// Most applications only need to select a [`UnicodeCompat`] level.
+/// `WidthOptions` additionally exposes knobs for behaviour that differs
+/// between terminal emulators and can therefore not be decided by this crate.
+/// All knobs are disabled by default so that `WidthOptions::new(compat)`
+/// always matches the functions that only accept a [`UnicodeCompat`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct WidthOptions {
+    pub(crate) compat: UnicodeCompat,
+    pub(crate) wide_bare_keycaps: bool,
+    pub(crate) stop_at_newline: bool,
+    pub(crate) control_policy: ControlPolicy,
+    pub(crate) replacement_width: Option<usize>,
+    pub(crate) split_unknown_zwj_sequences: bool,
+    pub(crate) pua_width: Option<usize>,
+    pub(crate) visualize_invisibles: bool,
+    pub(crate) ambiguous_wide: bool,
+    pub(crate) object_replacement_width: Option<usize>,
+    pub(crate) newline_width: Option<usize>,
+}
+
+impl WidthOptions {
+    /// Creates the default options for the given compatability level.
+    pub const fn new(compat: UnicodeCompat) -> WidthOptions {
+        WidthOptions {
+            compat,
+            wide_bare_keycaps: false,
+            stop_at_newline: false,
+            control_policy: ControlPolicy::Zero,
+            replacement_width: None,
+            split_unknown_zwj_sequences: false,
+            pua_width: None,
+            visualize_invisibles: false,
+            ambiguous_wide: false,
+            object_replacement_width: None,
+            newline_width: None,
+        }
+    }
+
+    /// Options for a modern terminal (like `kitty` or `windows terminal`)
+    /// in a CJK locale: [`UnicodeCompat::Unicode14`] with
+    /// [`ambiguous_wide`](WidthOptions::ambiguous_wide) enabled.
+    pub const fn cjk_modern() -> WidthOptions {
+        WidthOptions::new(UnicodeCompat::Unicode14).ambiguous_wide(true)
+    }
+
+    /// Options for an older terminal (like `xterm` or `alacritty`) in a western
+    /// locale: [`UnicodeCompat::Unicode9`] with ambiguous characters single width.
+    /// This is the same as `WidthOptions::default()`.
+    pub const fn western_legacy() -> WidthOptions {
+        WidthOptions::new(UnicodeCompat::Unicode9)
+    }
+
+    /// The unicode compatability level used for width calculation.
+    pub const fn unicode_compat(&self) -> UnicodeCompat {
+        self.compat
+    }
+
+    /// Treat a keycap base (`0`-`9`, `#` or `*`) directly followed by the
+    /// combining enclosing keycap (U+20E3) as double width even if the
+    /// emoji variation selector (VS16) is missing.
+    ///
+    /// Strictly speaking these sequences are not emoji and should be rendered
+    /// as a single width character with a combining mark. However, emulators
+    /// that shape graphemes with a color emoji font which contains ligatures
+    /// for these sequences (for example `wezterm` and `iterm` with the default
+    /// fonts) render them as a double width keycap emoji. Emulators that
+    /// use their own width tables like `kitty`, `alacritty`, `xterm`
+    /// and `kde konsole` render them single width.
+    pub const fn wide_bare_keycaps(mut self, enable: bool) -> WidthOptions {
+        self.wide_bare_keycaps = enable;
+        self
+    }
+
+    /// Only measure the first line of the text: width calculation stops at
+    /// the first newline (`\n`) instead of counting the following lines.
+    /// This avoids splitting the string when only the width of the first
+    /// physical line is required.
+    pub const fn stop_at_newline(mut self, enable: bool) -> WidthOptions {
+        self.stop_at_newline = enable;
+        self
+    }
+
+    /// Selects how ASCII control characters (including DEL) are measured,
+    /// see [`ControlPolicy`].
+    pub const fn control_policy(mut self, policy: ControlPolicy) -> WidthOptions {
+        self.control_policy = policy;
+        self
+    }
+
+    /// Only treat emoji ZWJ sequences that are recommended for general
+    /// interchange (RGI) as a single (double width) glyph. Other ZWJ sequences
+    /// are measured as the sum of the individual emoji that are joined.
+    ///
+    /// Emulators that shape graphemes with an emoji font render unknown
+    /// sequences as separate emoji because the font contains no glyph for them.
+    /// This option has no effect if the `emoji` feature is disabled
+    /// or the `ascii-only` feature is enabled.
+    pub const fn split_unknown_zwj_sequences(mut self, enable: bool) -> WidthOptions {
+        self.split_unknown_zwj_sequences = enable;
+        self
+    }
+
+    /// The width of the replacement character (U+FFFD) inserted for invalid
+    /// input by [`bytes_width_with`](crate::bytes_width_with) and `os_str_width_with`.
+    ///
+    /// By default the width of U+FFFD from the unicode tables (1) is used,
+    /// but some terminals render the replacement glyph double width.
+    pub const fn replacement_width(mut self, width: usize) -> WidthOptions {
+        self.replacement_width = Some(width);
+        self
+    }
+
+    /// The width of private use characters in the BMP (U+E000 to U+F8FF) and the
+    /// supplementary private use planes 15 (U+F0000 to U+FFFFD) and 16 (U+100000 to U+10FFFD).
+    ///
+    /// By default private use characters are single width. Icon fonts (for example
+    /// nerd fonts) place their glyphs in these ranges and some terminals render them
+    /// double width. The noncharacters at the end of planes 15 and 16 are not
+    /// private use characters and remain zero width.
+    pub const fn pua_width(mut self, width: usize) -> WidthOptions {
+        self.pua_width = Some(width);
+        self
+    }
+
+    /// Measure the invisible format characters zero width space (U+200B),
+    /// zero width non-joiner (U+200C), zero width joiner (U+200D) and
+    /// word joiner (U+2060) as single width.
+    ///
+    /// Editors that show invisible characters render these as a visible marker.
+    /// Combining marks and other zero width characters are not affected.
+    pub const fn visualize_invisibles(mut self, enable: bool) -> WidthOptions {
+        self.visualize_invisibles = enable;
+        self
+    }
+
+    /// Measure characters with ambiguous east asian width (for example `±`, `○`
+    /// or greek and cyrillic letters) as double width, like terminals that
+    /// are configured for a CJK locale do. See also [`str_width_cjk`](crate::str_width_cjk).
+    pub const fn ambiguous_wide(mut self, enable: bool) -> WidthOptions {
+        self.ambiguous_wide = enable;
+        self
+    }
+
+    /// The width of the object replacement character (U+FFFC).
+    ///
+    /// Rich text systems use U+FFFC as a placeholder for embedded objects
+    /// (for example images) that can occupy any number of columns.
+    /// By default the width from the unicode tables (1) is used.
+    pub const fn object_replacement_width(mut self, width: usize) -> WidthOptions {
+        self.object_replacement_width = Some(width);
+        self
+    }
+
+    /// The width of each newline (`\n`), for example to show a `↵` marker
+    /// at the end of each line like [`visualize_invisibles`](WidthOptions::visualize_invisibles).
+    ///
+    /// By default newlines are zero width. This takes precedence over the
+    /// [`control_policy`](WidthOptions::control_policy) for newlines.
+    pub const fn newline_width(mut self, width: usize) -> WidthOptions {
+        self.newline_width = Some(width);
+        self
+    }
+
+    /// Computes the width of `s` like [`str_width`](crate::str_width) but
+    /// applies the [`pua_width`](WidthOptions::pua_width),
+    /// [`visualize_invisibles`](WidthOptions::visualize_invisibles),
+    /// [`ambiguous_wide`](WidthOptions::ambiguous_wide),
+    /// [`object_replacement_width`](WidthOptions::object_replacement_width) and
+    /// [`newline_width`](WidthOptions::newline_width) overrides.
+    pub(crate) fn str_width(self, s: &str) -> usize {
+        if self.pua_width.is_none()
+            && !self.visualize_invisibles
+            && !self.ambiguous_wide
+            && self.object_replacement_width.is_none()
+            && self.newline_width.is_none()
+        {
+            return crate::str_width(s, self.compat);
+        }
+        char_width_indices(s, self.compat)
+            .map(|(_, c, width)| match self.pua_width {
+                Some(pua_width) if is_private_use(c) => pua_width,
+                _ if c == OBJECT_REPLACEMENT_CHARACTER => {
+                    self.object_replacement_width.unwrap_or(width)
+                }
+                _ if c == '\n' => self.newline_width.unwrap_or(width),
+                _ if self.visualize_invisibles && is_invisible(c) => 1,
+                _ if self.ambiguous_wide && width == 1 && is_ambiguous(c) => 2,
+                _ => width,
+            })
+            .sum()
+    }
+
+    /// The width that the control characters in `s` add on top of
+    /// the width of all other characters according to the [`ControlPolicy`].
+    /// Newlines are skipped if their width is set with [`newline_width`](WidthOptions::newline_width).
+    pub(crate) fn control_width(self, s: &str) -> usize {
+        let skip_newlines = self.newline_width.is_some();
+        match self.control_policy {
+            ControlPolicy::Zero => 0,
+            ControlPolicy::Caret => {
+                let controls = s
+                    .bytes()
+                    .filter(|&b| b.is_ascii_control() && !(skip_newlines && b == b'\n'))
+                    .count();
+                2 * controls
+            }
+        }
+    }
+}
+
+/// The object replacement character (U+FFFC)
+const OBJECT_REPLACEMENT_CHARACTER: char = '\u{FFFC}';
+
+/// Returns whether `c` is an invisible format character that is shown by
+/// [`WidthOptions::visualize_invisibles`].
+#[inline]
+fn is_invisible(c: char) -> bool {
+    matches!(c, '\u{200B}'..='\u{200D}' | '\u{2060}')
+}
+
+/// Returns whether `c` is a private use character of the BMP or
+/// one of the two supplementary private use planes.
+#[inline]
+pub(crate) fn is_private_use(c: char) -> bool {
+    matches!(
+        c,
+        '\u{E000}'..='\u{F8FF}' | '\u{F0000}'..='\u{FFFFD}' | '\u{100000}'..='\u{10FFFD}'
+    )
+}
+
+/// Determines the width of ASCII control characters (`0x00`-`0x1F` and DEL `0x7F`).
+///
+/// Terminals do not render control characters, so by default they are zero width.
+/// However many applications (for example shells and editors) display them
+/// in caret notation instead of sending them to the terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum ControlPolicy {
+    /// Control characters are not rendered and have width zero.
+    #[default]
+    Zero,
+    /// Control characters are rendered in caret notation (`^A`, `^?` for DEL)
+    /// and are two columns wide.
+    Caret,
+}
+
+/// Caret notation of the C0 control characters (`0x00`-`0x1F`)
+const C0_CARET_NOTATION: [&str; 32] = [
+    "^@", "^A", "^B", "^C", "^D", "^E", "^F", "^G", "^H", "^I", "^J", "^K", "^L", "^M", "^N", "^O",
+    "^P", "^Q", "^R", "^S", "^T", "^U", "^V", "^W", "^X", "^Y", "^Z", "^[", "^\\", "^]", "^^",
+    "^_",
+];
+
+/// Returns the caret notation (for example `^C` for U+0003 and `^?` for DEL)
+/// of an ASCII control character or `None` for any other character.
+///
+/// This is how control characters are rendered with [`ControlPolicy::Caret`],
+/// the returned string is always two columns wide.
+pub fn caret_notation(c: char) -> Option<&'static str> {
+    match c {
+        '\x7f' => Some("^?"),
+        '\0'..='\x1f' => Some(C0_CARET_NOTATION[c as usize]),
+        _ => None,
+    }
+}
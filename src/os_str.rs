@@ -0,0 +1,40 @@
+use std::ffi::OsStr;
+
+use crate::{UnicodeCompat, WidthOptions};
+
+/// Computes the width of an `OsStr` (for example a file name).
+///
+/// `OsStr` is not guaranteed to be valid unicode. Invalid sequences are
+/// replaced with the replacement character U+FFFD (which is single width)
+/// just like [`OsStr::to_string_lossy`]. This allows laying out arbitrary
+/// paths, as long as the replacement character is also used for rendering.
+pub fn os_str_width(s: &OsStr, unicode_compat: UnicodeCompat) -> usize {
+    os_str_width_with(s, WidthOptions::new(unicode_compat))
+}
+
+/// Computes the width of an `OsStr` like [`os_str_width`].
+///
+/// Each inserted replacement character is [`WidthOptions::replacement_width`] wide.
+/// The other options are ignored.
+pub fn os_str_width_with(s: &OsStr, options: WidthOptions) -> usize {
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+        crate::bytes_width_with(s.as_bytes(), options)
+    }
+    #[cfg(not(unix))]
+    {
+        match s.to_str() {
+            Some(s) => crate::str_width(s, options.compat),
+            None => {
+                // replacement characters that were already present in `s`
+                // can not be distinguished from the inserted ones here
+                let lossy = s.to_string_lossy();
+                let replacements = lossy.matches(char::REPLACEMENT_CHARACTER).count();
+                crate::str_width(&lossy, options.compat)
+                    - replacements * crate::char_width_unicode9(char::REPLACEMENT_CHARACTER)
+                    + replacements * crate::bytes::replacement_width(options)
+            }
+        }
+    }
+}
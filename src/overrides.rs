@@ -0,0 +1,147 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::RangeInclusive;
+
+use crate::{char_width_indices, UnicodeCompat};
+
+/// Custom widths for individual characters or ranges of characters that take
+/// precedence over the unicode tables.
+///
+/// This allows loading the width profile of a font at runtime, for example
+/// nerd fonts that place double width icons in the private use area.
+/// Overrides are stored as a sorted list of ranges, so lookup is a binary search.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct WidthOverrides {
+    /// Sorted, non overlapping `(start, end, width)` ranges
+    ranges: Vec<(u32, u32, usize)>,
+}
+
+impl WidthOverrides {
+    /// Creates an empty set of overrides.
+    pub const fn new() -> WidthOverrides {
+        WidthOverrides { ranges: Vec::new() }
+    }
+
+    /// Overrides the width of `c`.
+    pub fn set(&mut self, c: char, width: usize) {
+        self.set_range(c..=c, width)
+    }
+
+    /// Overrides the width of all characters in `range`.
+    ///
+    /// Overrides that were set previously are replaced where they overlap with `range`.
+    pub fn set_range(&mut self, range: RangeInclusive<char>, width: usize) {
+        let (start, end) = (*range.start() as u32, *range.end() as u32);
+        if start > end {
+            return;
+        }
+        let mut ranges = Vec::with_capacity(self.ranges.len() + 2);
+        for &(range_start, range_end, range_width) in &self.ranges {
+            if range_end < start || range_start > end {
+                ranges.push((range_start, range_end, range_width));
+                continue;
+            }
+            if range_start < start {
+                ranges.push((range_start, start - 1, range_width));
+            }
+            if range_end > end {
+                ranges.push((end + 1, range_end, range_width));
+            }
+        }
+        let i = ranges.partition_point(|&(range_start, _, _)| range_start < start);
+        ranges.insert(i, (start, end, width));
+        self.ranges = ranges;
+    }
+
+    /// Parses overrides from `codepoints;width` lines and adds them with [`set_range`](WidthOverrides::set_range).
+    ///
+    /// This is the same format accepted by `cargo xtask gen-tables --overrides`:
+    /// `codepoints` is either a single hexadecimal codepoint (`E0A0`) or an
+    /// inclusive range (`E0A0..E0A3`). Empty lines and comments starting with `#`
+    /// are ignored. If a line is invalid, none of the overrides are applied.
+    pub fn set_from_str(&mut self, s: &str) -> Result<(), InvalidOverride> {
+        let mut parsed = Vec::new();
+        for (i, line) in s.lines().enumerate() {
+            let line = line.split_once('#').map_or(line, |(line, _comment)| line);
+            if line.trim().is_empty() {
+                continue;
+            }
+            match parse_override(line) {
+                Some(entry) => parsed.push(entry),
+                None => return Err(InvalidOverride(i + 1)),
+            }
+        }
+        for (range, width) in parsed {
+            self.set_range(range, width);
+        }
+        Ok(())
+    }
+
+    /// Returns the width override for `c` or `None` if its width is not overridden.
+    pub fn get(&self, c: char) -> Option<usize> {
+        let c = c as u32;
+        let i = self
+            .ranges
+            .binary_search_by(|&(start, end, _)| {
+                if end < c {
+                    Ordering::Less
+                } else if start > c {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .ok()?;
+        Some(self.ranges[i].2)
+    }
+
+    /// Computes the width of `s` like [`str_width`](crate::str_width) but uses
+    /// the overridden width for the characters that have one.
+    pub fn str_width(&self, s: &str, unicode_compat: UnicodeCompat) -> usize {
+        if self.ranges.is_empty() {
+            return crate::str_width(s, unicode_compat);
+        }
+        char_width_indices(s, unicode_compat)
+            .map(|(_, c, width)| self.get(c).unwrap_or(width))
+            .sum()
+    }
+}
+
+/// Parses a single `codepoints;width` line.
+fn parse_override(line: &str) -> Option<(RangeInclusive<char>, usize)> {
+    let (codepoints, width) = line.split_once(';')?;
+    let (start, end) = match codepoints.trim().split_once("..") {
+        Some(range) => range,
+        None => (codepoints.trim(), codepoints.trim()),
+    };
+    let start = char::from_u32(u32::from_str_radix(start, 16).ok()?)?;
+    let end = char::from_u32(u32::from_str_radix(end, 16).ok()?)?;
+    let width = width.trim().parse().ok()?;
+    Some((start..=end, width))
+}
+
+/// Error returned by [`WidthOverrides::set_from_str`] for a line that
+/// is not a valid `codepoints;width` override.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InvalidOverride(usize);
+
+impl InvalidOverride {
+    /// The (one based) number of the invalid line.
+    pub const fn line(&self) -> usize {
+        self.0
+    }
+}
+
+impl fmt::Display for InvalidOverride {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid width override on line {}, expected `codepoints;width`",
+            self.0
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidOverride {}
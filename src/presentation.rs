@@ -0,0 +1,48 @@
+use crate::emoji_presentation::EMOJI_PRESENTATION;
+use crate::{debug_check_variation_selector, is_emoji_variation};
+
+/// The presentation of an emoji.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Presentation {
+    /// Text presentation: the emoji is rendered like a regular
+    /// (usually single width) character
+    Text,
+    /// Emoji presentation: the emoji is rendered as a colorful double width pictograph
+    Emoji,
+}
+
+/// Determines the presentation of the emoji `c` that is followed by `rem`.
+///
+/// Returns `None` if `c` is not an emoji. Otherwise the presentation selected
+/// by a variation selector (VS15/VS16) directly following `c` is returned.
+/// Without an explicit selector (or if the presentation of `c` can not be changed)
+/// the default presentation of `c` is returned.
+///
+/// Note that this always resolves the presentation according to Unicode 14.
+/// Emulators that use Unicode 9 width (see [`UnicodeCompat::Unicode9`](crate::UnicodeCompat::Unicode9))
+/// may ignore the variation selectors.
+pub fn resolve_presentation(c: char, rem: &str) -> Option<Presentation> {
+    let default = if EMOJI_PRESENTATION.contains_char(c) {
+        Presentation::Emoji
+    } else if is_emoji_variation(c) {
+        Presentation::Text
+    } else {
+        return None;
+    };
+    if is_emoji_variation(c) {
+        match rem.as_bytes() {
+            // text variant select U-FE0E as bytes
+            [0xef, 0xb8, 0x8e, ..] => {
+                debug_check_variation_selector(rem, Some('\u{FE0E}'));
+                return Some(Presentation::Text);
+            }
+            // emoji variant select U-FE0F as bytes
+            [0xef, 0xb8, 0x8f, ..] => {
+                debug_check_variation_selector(rem, Some('\u{FE0F}'));
+                return Some(Presentation::Emoji);
+            }
+            _ => debug_check_variation_selector(rem, None),
+        }
+    }
+    Some(default)
+}
@@ -0,0 +1,124 @@
+#[cfg(feature = "std")]
+use std::ffi::OsString;
+
+use crate::UnicodeCompat;
+
+/// Presets for the width behaviour of common terminal emulators.
+///
+/// Selecting a profile (for example from a config option) configures
+/// all width related settings that differ between emulators at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum TerminalProfile {
+    /// [`kitty`](https://sw.kovidgoyal.net/kitty/)
+    Kitty,
+    /// [`wezterm`](https://wezfurlong.org/wezterm/) with its default configuration
+    WezTerm,
+    /// [`alacritty`](https://alacritty.org/)
+    Alacritty,
+    /// [`kde konsole`](https://konsole.kde.org/)
+    Konsole,
+    /// [`windows terminal`](https://github.com/microsoft/terminal), also used
+    /// for `windows cmd` and `windows powershell`
+    WindowsTerminal,
+    /// [`xterm`](https://invisible-island.net/xterm/), including older
+    /// versions that use tables from before unicode 9
+    Xterm,
+    /// [`mintty`](https://mintty.github.io/), also used by `git bash`, `cygwin` and `msys2`.
+    /// Emoji are rendered as wide glyphs but variation selectors do not change their width
+    Mintty,
+    /// [`PuTTY`](https://www.chiark.greenend.org.uk/~sgtatham/putty/), uses its own
+    /// width tables derived from `wcwidth` and ignores variation selectors
+    Putty,
+    /// Any other emulator, uses the most widely supported settings
+    #[default]
+    Generic,
+}
+
+impl TerminalProfile {
+    /// The unicode compatability level used by the emulator,
+    /// see the documentation of [`UnicodeCompat`].
+    pub const fn unicode_compat(self) -> UnicodeCompat {
+        match self {
+            TerminalProfile::Kitty | TerminalProfile::WindowsTerminal => UnicodeCompat::Unicode14,
+            TerminalProfile::WezTerm
+            | TerminalProfile::Alacritty
+            | TerminalProfile::Konsole
+            | TerminalProfile::Xterm
+            | TerminalProfile::Mintty
+            | TerminalProfile::Putty
+            | TerminalProfile::Generic => UnicodeCompat::Unicode9,
+        }
+    }
+
+    /// Looks up a profile by the (case insensitive) name of the emulator,
+    /// for example to parse a config option. Returns `None` for unknown names.
+    ///
+    /// The accepted names are `kitty`, `wezterm`, `alacritty`, `konsole`,
+    /// `windows-terminal`, `xterm`, `mintty`, `putty` and `generic`.
+    pub fn from_name(name: &str) -> Option<TerminalProfile> {
+        const NAMES: [(&str, TerminalProfile); 9] = [
+            ("kitty", TerminalProfile::Kitty),
+            ("wezterm", TerminalProfile::WezTerm),
+            ("alacritty", TerminalProfile::Alacritty),
+            ("konsole", TerminalProfile::Konsole),
+            ("windows-terminal", TerminalProfile::WindowsTerminal),
+            ("xterm", TerminalProfile::Xterm),
+            ("mintty", TerminalProfile::Mintty),
+            ("putty", TerminalProfile::Putty),
+            ("generic", TerminalProfile::Generic),
+        ];
+        NAMES
+            .iter()
+            .find(|(profile_name, _)| profile_name.eq_ignore_ascii_case(name))
+            .map(|&(_, profile)| profile)
+    }
+}
+
+#[cfg(feature = "std")]
+impl TerminalProfile {
+    /// Guesses the emulator the process is running in from the `WT_SESSION`,
+    /// `TERM_PROGRAM` and `TERM` environment variables.
+    ///
+    /// This is only a heuristic: environment variables are inherited by
+    /// child processes (for example across `ssh` or inside `tmux`) and not
+    /// all emulators set them. [`TerminalProfile::Generic`] is returned if
+    /// the emulator can not be identified.
+    pub fn detect() -> TerminalProfile {
+        TerminalProfile::detect_from(|name| std::env::var_os(name))
+    }
+
+    /// Implementation of [`TerminalProfile::detect`] with the environment
+    /// lookup passed in, so that it can be tested without modifying the
+    /// environment of the process.
+    pub(crate) fn detect_from(lookup: impl Fn(&str) -> Option<OsString>) -> TerminalProfile {
+        if lookup("WT_SESSION").is_some() {
+            return TerminalProfile::WindowsTerminal;
+        }
+        match lookup("TERM_PROGRAM") {
+            Some(program) if program == "WezTerm" => return TerminalProfile::WezTerm,
+            Some(program) if program == "mintty" => return TerminalProfile::Mintty,
+            _ => (),
+        }
+        let term = lookup("TERM")
+            .and_then(|term| term.into_string().ok())
+            .unwrap_or_default();
+        match term.as_str() {
+            "xterm-kitty" => TerminalProfile::Kitty,
+            "wezterm" => TerminalProfile::WezTerm,
+            "alacritty" | "alacritty-direct" => TerminalProfile::Alacritty,
+            _ if term.starts_with("konsole") => TerminalProfile::Konsole,
+            _ if term.starts_with("putty") => TerminalProfile::Putty,
+            _ => TerminalProfile::Generic,
+        }
+    }
+}
+
+/// Guesses the unicode compatability level of the terminal the process is running in.
+///
+/// This is a heuristic based on environment variables, see [`TerminalProfile::detect`].
+/// Returns [`UnicodeCompat::Unicode9`] if the terminal is unknown. Applications
+/// should still allow users to override the detected value.
+#[cfg(feature = "std")]
+pub fn detect_unicode_compat() -> UnicodeCompat {
+    TerminalProfile::detect().unicode_compat()
+}
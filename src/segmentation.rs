@@ -0,0 +1,15 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{grapheme_width, UnicodeCompat};
+
+/// Computes the width of a string by summing up the widths of its
+/// extended grapheme clusters (see [`grapheme_width`]).
+///
+/// Unlike [`str_width`](crate::str_width) this uses the full unicode
+/// segmentation algorithm, so emoji ZWJ sequences, flags and emoji modifier
+/// sequences are always measured as a single (at most double width) grapheme.
+pub fn str_width_segmented(s: &str, unicode_compat: UnicodeCompat) -> usize {
+    s.graphemes(true)
+        .map(|grapheme| grapheme_width(grapheme, unicode_compat))
+        .sum()
+}
@@ -0,0 +1,56 @@
+use crate::{str_width, UnicodeCompat};
+
+/// A cache for the width of the last measured string.
+///
+/// This is a lightweight alternative to `WidthCache` for loops that measure
+/// the same string over and over again (for example a label that is re-measured
+/// every frame of an animation). It does not allocate and is available without `std`.
+///
+/// Strings are identified by their address and length only, so checking for
+/// a hit does not even read the text. However, this means that the cached width is
+/// returned for any string that is stored at the same address with the same length.
+/// If a string is modified in place or dropped and a different string of the same
+/// length is allocated at the same address, a stale width is returned.
+/// Call [`clear`](SingleWidthCache::clear) after modifying the measured string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SingleWidthCache {
+    compat: UnicodeCompat,
+    /// Address, length and width of the last measured string
+    last: Option<(usize, usize, usize)>,
+}
+
+impl SingleWidthCache {
+    /// Creates an empty cache for the given compatability level.
+    pub const fn new(unicode_compat: UnicodeCompat) -> SingleWidthCache {
+        SingleWidthCache {
+            compat: unicode_compat,
+            last: None,
+        }
+    }
+
+    /// Computes the width of `s` or returns the cached width if `s` is the
+    /// same slice that was measured last.
+    pub fn width(&mut self, s: &str) -> usize {
+        let key = (s.as_ptr() as usize, s.len());
+        match self.last {
+            Some((ptr, len, width)) if (ptr, len) == key => width,
+            _ => {
+                let width = str_width(s, self.compat);
+                self.last = Some((key.0, key.1, width));
+                width
+            }
+        }
+    }
+
+    /// Returns whether calling [`width`](SingleWidthCache::width) with `s` returns the cached width.
+    pub fn is_cached(&self, s: &str) -> bool {
+        self.last.map_or(false, |(ptr, len, _)| {
+            (ptr, len) == (s.as_ptr() as usize, s.len())
+        })
+    }
+
+    /// Removes the cached width.
+    pub fn clear(&mut self) {
+        self.last = None
+    }
+}
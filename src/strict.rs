@@ -0,0 +1,101 @@
+use core::fmt;
+
+use crate::options::is_private_use;
+#[cfg(not(feature = "ascii-only"))]
+use crate::unassigned::UNASSIGNED;
+use crate::{char_width, char_width_indices, UnicodeCompat};
+
+/// Error returned by [`try_char_width`] and [`try_codepoint_width`] for
+/// codepoints that have no well defined width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WidthError {
+    /// A control character (general category `Cc`), for example a newline or DEL.
+    Control,
+    /// A codepoint that is not assigned to any character in the supported
+    /// unicode version, or that is outside the unicode codespace.
+    Unassigned,
+    /// A surrogate codepoint (U+D800 to U+DFFF). Surrogates can not be
+    /// represented as a `char`, so this is only returned by [`try_codepoint_width`].
+    Surrogate,
+}
+
+impl fmt::Display for WidthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            WidthError::Control => "control characters have no width",
+            WidthError::Unassigned => "unassigned codepoints have no width",
+            WidthError::Surrogate => "surrogate codepoints have no width",
+        };
+        f.write_str(msg)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WidthError {}
+
+/// Returns whether `c` is not assigned to any character.
+/// Always returns `false` if the `ascii-only` feature is enabled.
+#[inline]
+fn is_unassigned(c: char) -> bool {
+    #[cfg(not(feature = "ascii-only"))]
+    {
+        UNASSIGNED.contains_char(c)
+    }
+    #[cfg(feature = "ascii-only")]
+    {
+        let _ = c;
+        false
+    }
+}
+
+/// Calculates the width of a single character like [`char_width_unicode9`](crate::char_width_unicode9)
+/// and [`char_width_unicode14`](crate::char_width_unicode14) but returns an error instead of
+/// a fallback width for control characters and unassigned codepoints.
+///
+/// Note that noncharacters like U+FFFF are also unassigned.
+/// With the `ascii-only` feature unassigned codepoints are not detected.
+pub fn try_char_width(c: char, unicode_compat: UnicodeCompat) -> Result<usize, WidthError> {
+    if c.is_control() {
+        return Err(WidthError::Control);
+    }
+    if is_unassigned(c) {
+        return Err(WidthError::Unassigned);
+    }
+    Ok(char_width(c, "", unicode_compat))
+}
+
+/// Calculates the width of a raw codepoint like [`try_char_width`], additionally
+/// rejecting surrogates and values outside of the unicode codespace.
+pub fn try_codepoint_width(
+    codepoint: u32,
+    unicode_compat: UnicodeCompat,
+) -> Result<usize, WidthError> {
+    match char::from_u32(codepoint) {
+        Some(c) => try_char_width(c, unicode_compat),
+        None if (0xD800..=0xDFFF).contains(&codepoint) => Err(WidthError::Surrogate),
+        None => Err(WidthError::Unassigned),
+    }
+}
+
+/// Computes the width of `s` like [`str_width`](crate::str_width) but asks `fallback`
+/// for the width of characters that have no well defined width: unassigned codepoints
+/// and private use characters. If `fallback` returns `None` the normal width is used.
+///
+/// This allows injecting knowledge about the font, for example that all icons
+/// placed in the private use area are double width.
+/// With the `ascii-only` feature unassigned codepoints are not detected.
+pub fn str_width_with_fallback<F: Fn(char) -> Option<usize>>(
+    s: &str,
+    fallback: F,
+    unicode_compat: UnicodeCompat,
+) -> usize {
+    char_width_indices(s, unicode_compat)
+        .map(|(_, c, width)| {
+            if is_unassigned(c) || is_private_use(c) {
+                fallback(c).unwrap_or(width)
+            } else {
+                width
+            }
+        })
+        .sum()
+}
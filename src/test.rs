@@ -1,12 +1,34 @@
-use crate::str_width;
-use crate::{UnicodeCompat::Unicode14, UnicodeCompat::Unicode9};
+// most tests check the widths from the unicode tables and are skipped with `ascii-only`
+#![cfg_attr(feature = "ascii-only", allow(unused_imports))]
+
+use std::borrow::Cow;
+use std::fmt::{self, Write as _};
+
+use crate::{
+    align_row, bytes_width, caret_notation, char_width_indices, char_width_unicode14,
+    char_width_unicode9, char_width_unicode9_vs, checked_str_width, checked_str_width_after,
+    cluster_widths, codepoint_width, codepoints_with_width, column_range, display_width,
+    grapheme_indices, grapheme_width, grapheme_width_with, last_grapheme_width,
+    next_grapheme_width, packed_width, pad_into, pad_to_width, pad_to_width_cow,
+    pad_to_width_directed, rev_iter_width, same_width, split_fit, str_width, str_width_ansi,
+    str_width_cjk, str_width_contextual, str_width_with, str_width_with_fallback, take_width,
+    trimmed_width, truncate_ellipsis_into, truncate_to_width, truncate_to_width_marked,
+    try_char_width, try_codepoint_width, wcswidth, wcwidth, width_delta, width_until,
+    width_until_byte, wrap, wrap_chars, wrap_iter, Align, ColumnTracker, ControlPolicy, Direction,
+    GraphemeMeasurer, GridWriter, InvalidWidth, SingleWidthCache, TerminalProfile, Width,
+    WidthError, WidthOptions, WidthOverrides,
+};
+use crate::{UnicodeCompat, UnicodeCompat::Unicode14, UnicodeCompat::Unicode9};
 
 #[test]
+#[cfg(not(feature = "ascii-only"))]
 fn issue_1161() {
     assert_eq!(str_width("\u{3000}", Unicode14), 2);
 }
 
 #[test]
+#[cfg(feature = "emoji")]
+#[cfg(not(feature = "ascii-only"))]
 fn issue_997() {
     let victory_hand = "\u{270c}";
     let victory_hand_text_presentation = "\u{270c}\u{fe0e}";
@@ -29,6 +51,7 @@ fn issue_997() {
 }
 
 #[test]
+#[cfg(not(feature = "ascii-only"))]
 fn issue_1573() {
     let sequence = "\u{1112}\u{1161}\u{11ab}";
     assert_eq!(str_width(sequence, Unicode14), 2);
@@ -49,6 +72,8 @@ fn issue_5502() {
 }
 
 #[test]
+#[cfg(feature = "emoji")]
+#[cfg(not(feature = "ascii-only"))]
 fn emoji_representation() {
     // its annoying but we don't grapheme segment so each emoji must be calcultade indivudlaly
     assert_eq!(str_width("👩‍❤️‍👨", Unicode9), 5);
@@ -56,3 +81,2129 @@ fn emoji_representation() {
     assert_eq!(str_width("✔️", Unicode9), 1);
     assert_eq!(str_width("✔️", Unicode14), 2);
 }
+
+#[test]
+#[cfg(not(feature = "emoji"))]
+fn emoji_feature_disabled() {
+    assert_eq!(str_width("✔️", Unicode14), 1);
+    assert_eq!(str_width("✔️", Unicode9), 1);
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn bare_keycap() {
+    let bare_keycap = "1\u{20E3}";
+    assert_eq!(grapheme_width(bare_keycap, Unicode9), 1);
+    assert_eq!(grapheme_width(bare_keycap, Unicode14), 1);
+    let options = WidthOptions::new(Unicode14).wide_bare_keycaps(true);
+    assert_eq!(grapheme_width_with(bare_keycap, options), 2);
+    let options = WidthOptions::new(Unicode9).wide_bare_keycaps(true);
+    assert_eq!(grapheme_width_with(bare_keycap, options), 2);
+    assert_eq!(grapheme_width_with("a\u{20E3}", options), 1);
+}
+
+#[test]
+#[cfg(feature = "emoji")]
+#[cfg(not(feature = "ascii-only"))]
+fn keycap() {
+    let keycap = "1\u{FE0F}\u{20E3}";
+    assert_eq!(grapheme_width(keycap, Unicode9), 1);
+    assert_eq!(grapheme_width(keycap, Unicode14), 2);
+    assert_eq!(str_width("#\u{FE0F}", Unicode14), 2);
+    assert_eq!(str_width("#\u{FE0E}", Unicode14), 1);
+    assert_eq!(str_width("#", Unicode14), 1);
+}
+
+#[test]
+#[cfg(feature = "emoji")]
+#[cfg(not(feature = "ascii-only"))]
+fn keycap_base_emoji_presentation() {
+    // with unicode 14 VS16 selects the (double width) emoji presentation
+    // of the keycap bases just like for other emoji variation bases
+    for base in ['0', '5', '9', '#', '*'] {
+        let emoji = format!("{base}\u{FE0F}");
+        let keycap = format!("{base}\u{FE0F}\u{20E3}");
+        assert_eq!(str_width(&emoji, Unicode14), 2);
+        assert_eq!(str_width(&keycap, Unicode14), 2);
+        assert_eq!(char_width_unicode14(base, "\u{FE0F}"), 2);
+        assert_eq!(str_width(&emoji, Unicode9), 1);
+        assert_eq!(str_width(&keycap, Unicode9), 1);
+        // without VS16 (or with VS15) the keycap bases are plain ASCII characters
+        assert_eq!(str_width(&format!("{base}\u{FE0E}"), Unicode14), 1);
+        assert_eq!(str_width(&format!("{base}\u{20E3}"), Unicode14), 1);
+        assert_eq!(str_width(&format!("{base}{base}"), Unicode14), 2);
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn next_grapheme() {
+    assert_eq!(next_grapheme_width("", Unicode9), None);
+    assert_eq!(next_grapheme_width("ab", Unicode9), Some((1, 1)));
+    assert_eq!(next_grapheme_width("\u{4E00}a", Unicode9), Some((2, 3)));
+    assert_eq!(
+        next_grapheme_width("e\u{301}\u{302}a", Unicode9),
+        Some((1, 5))
+    );
+    assert_eq!(next_grapheme_width("\r\na", Unicode9), Some((0, 2)));
+    let family = "👩‍❤️‍👨";
+    assert_eq!(
+        next_grapheme_width(family, Unicode9),
+        Some((2, family.len()))
+    );
+    assert_eq!(
+        next_grapheme_width(family, Unicode14),
+        Some((2, family.len()))
+    );
+    let flags = "🇩🇪🇫🇷";
+    assert_eq!(next_grapheme_width(flags, Unicode9), Some((2, 8)));
+    assert_eq!(next_grapheme_width("👍🏽a", Unicode9), Some((2, 8)));
+}
+
+#[test]
+#[cfg(feature = "emoji")]
+#[cfg(not(feature = "ascii-only"))]
+fn next_grapheme_emoji_presentation() {
+    assert_eq!(next_grapheme_width("✔️a", Unicode9), Some((1, 6)));
+    assert_eq!(next_grapheme_width("✔️a", Unicode14), Some((2, 6)));
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn zwj_between_non_emoji() {
+    // a ZWJ only joins emoji (GB11), other characters remain separate graphemes
+    let cjk = "\u{7532}\u{200D}\u{4E59}";
+    let ascii = "a\u{200D}b";
+    for compat in [Unicode9, Unicode14] {
+        assert_eq!(next_grapheme_width(cjk, compat), Some((2, 6)));
+        assert_eq!(next_grapheme_width(ascii, compat), Some((1, 4)));
+        assert_eq!(last_grapheme_width(cjk, compat), Some((2, 3)));
+        assert_eq!(last_grapheme_width(ascii, compat), Some((1, 1)));
+        assert_eq!(cluster_widths(cjk, compat), [(0..6, 2), (6..9, 2)]);
+        assert_eq!(cluster_widths(ascii, compat), [(0..4, 1), (4..5, 1)]);
+        assert_eq!(
+            GraphemeMeasurer::new(cjk, compat).collect::<Vec<_>>(),
+            [("\u{7532}\u{200D}", 2), ("\u{4E59}", 2)]
+        );
+        assert_eq!(take_width(cjk, 4, compat), (4, 9));
+        assert_eq!(take_width(cjk, 3, compat), (2, 6));
+        assert_eq!(truncate_to_width(ascii, 1, compat), "a\u{200D}");
+        assert_eq!(split_fit(cjk, 2, compat), ("\u{7532}\u{200D}", "\u{4E59}"));
+        assert_eq!(wrap(cjk, 2, compat), ["\u{7532}\u{200D}", "\u{4E59}"]);
+        assert_eq!(wrap(ascii, 1, compat), ["a\u{200D}", "b"]);
+        assert_eq!(
+            wrap_chars(cjk.chars(), 2, compat).collect::<Vec<_>>(),
+            [vec!['\u{7532}', '\u{200D}'], vec!['\u{4E59}']]
+        );
+        assert_eq!(grapheme_indices(ascii).count(), 2);
+    }
+}
+
+#[test]
+fn stop_at_newline() {
+    let options = WidthOptions::new(Unicode9);
+    assert_eq!(str_width_with("abc\ndef", options), 6);
+    assert_eq!(str_width_with("abc\ndef", options.stop_at_newline(true)), 3);
+    assert_eq!(
+        str_width_with("abc\r\ndef", options.stop_at_newline(true)),
+        3
+    );
+    assert_eq!(str_width_with("abc", options.stop_at_newline(true)), 3);
+}
+
+#[test]
+fn delete_control_policy() {
+    let options = WidthOptions::new(Unicode9);
+    assert_eq!(str_width("\x7f", Unicode9), 0);
+    assert_eq!(str_width_with("\x7f", options), 0);
+    let zero = options.control_policy(ControlPolicy::Zero);
+    assert_eq!(str_width_with("\x7f", zero), 0);
+    let caret = options.control_policy(ControlPolicy::Caret);
+    assert_eq!(str_width_with("\x7f", caret), 2);
+    assert_eq!(str_width_with("a\x01b\x7f", caret), 6);
+    assert_eq!(grapheme_width_with("\x7f", caret), 2);
+    assert_eq!(grapheme_width_with("\x7f", zero), 0);
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn column_tracker_carriage_return() {
+    let mut tracker = ColumnTracker::new(Unicode9);
+    tracker.push_str("abc\rX");
+    assert_eq!(tracker.column(), 1);
+    assert_eq!(tracker.line(), 0);
+    assert_eq!(str_width("abc\rX", Unicode9), 4);
+
+    let mut tracker = ColumnTracker::new(Unicode9);
+    tracker.push_str("abc\r\nde");
+    assert_eq!(tracker.column(), 2);
+    assert_eq!(tracker.line(), 1);
+    tracker.push_str("\n\n\u{4E00}");
+    assert_eq!(tracker.column(), 2);
+    assert_eq!(tracker.line(), 3);
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn grid_writer_wraps_wide_chars() {
+    let mut grid = GridWriter::new(4);
+    assert_eq!(grid.write_char('a', Unicode9), (0, 0));
+    assert_eq!(grid.write_char('b', Unicode9), (0, 1));
+    assert_eq!(grid.write_char('c', Unicode9), (0, 2));
+    // a wide char does not fit into the last column and leaves it blank
+    assert_eq!(grid.write_char('你', Unicode9), (1, 0));
+    assert_eq!(grid.write_char('好', Unicode9), (1, 2));
+    assert_eq!((grid.row(), grid.col()), (1, 4));
+    // the next character wraps
+    assert_eq!(grid.write_char('d', Unicode9), (2, 0));
+    // combining marks are attached to the previous character
+    assert_eq!(grid.write_char('\u{301}', Unicode9), (2, 0));
+    assert_eq!(grid.write_char('e', Unicode9), (2, 1));
+    assert_eq!(grid.write_char('\r', Unicode9), (2, 0));
+    assert_eq!(grid.write_char('\n', Unicode9), (3, 0));
+    assert_eq!(grid.write_char('\x07', Unicode9), (3, 0));
+    assert_eq!(grid.write_char('f', Unicode9), (3, 0));
+
+    // wide characters are placed even if the grid is only one column wide
+    let mut grid = GridWriter::new(1);
+    assert_eq!(grid.write_char('你', Unicode9), (0, 0));
+    assert_eq!(grid.write_char('好', Unicode9), (1, 0));
+    assert_eq!(grid.write_char('a', Unicode9), (2, 0));
+}
+
+#[test]
+#[cfg(feature = "emoji")]
+#[cfg(not(feature = "ascii-only"))]
+fn grid_writer_variation_selector() {
+    let mut grid = GridWriter::new(3);
+    assert_eq!(grid.write_char('✔', Unicode14), (0, 0));
+    assert_eq!(grid.write_char('\u{FE0F}', Unicode14), (0, 0));
+    assert_eq!(grid.col(), 2);
+    assert_eq!(grid.write_char('✔', Unicode14), (0, 2));
+    // the emoji presentation does not fit into the last column
+    assert_eq!(grid.write_char('\u{FE0F}', Unicode14), (0, 2));
+    assert_eq!(grid.col(), 3);
+
+    let mut grid = GridWriter::new(3);
+    grid.write_char('✔', Unicode9);
+    grid.write_char('\u{FE0F}', Unicode9);
+    assert_eq!(grid.col(), 1);
+}
+
+#[test]
+#[cfg(feature = "emoji")]
+#[cfg(not(feature = "ascii-only"))]
+fn column_tracker_variation_selector() {
+    let mut tracker = ColumnTracker::new(Unicode14);
+    tracker.push('✔');
+    assert_eq!(tracker.column(), 1);
+    tracker.push('\u{FE0F}');
+    assert_eq!(tracker.column(), 2);
+    tracker.push('\u{FE0F}');
+    assert_eq!(tracker.column(), 2);
+
+    let mut tracker = ColumnTracker::new(Unicode9);
+    tracker.push_str("✔️");
+    assert_eq!(tracker.column(), 1);
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn ansi_escape_sequences() {
+    assert_eq!(str_width_ansi("\x1b[1;31mred\x1b[0m", Unicode9), 3);
+    assert_eq!(str_width_ansi("\x1b(Bab\x1b7c", Unicode9), 3);
+    assert_eq!(str_width_ansi("\u{4E00}\x1b[m\u{4E00}", Unicode14), 4);
+}
+
+#[test]
+fn ansi_hyperlinks() {
+    let link = "see \x1b]8;;https://example.com\x1b\\here\x1b]8;;\x1b\\!";
+    assert_eq!(str_width_ansi(link, Unicode9), 9);
+    let link = "\x1b]8;id=1;https://example.com\x07here\x1b]8;;\x07";
+    assert_eq!(str_width_ansi(link, Unicode14), 4);
+    // an unterminated OSC must not hide the rest of the string
+    assert_eq!(str_width_ansi("\x1b]8;;abc", Unicode9), 6);
+    assert_eq!(str_width_ansi("\x1b]8;;abc\x1b[mdef", Unicode9), 9);
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn char_width_indices_attributes_variation() {
+    let indices: Vec<_> = char_width_indices("a✔️b", Unicode9).collect();
+    assert_eq!(
+        indices,
+        [(0, 'a', 1), (1, '✔', 1), (4, '\u{FE0F}', 0), (7, 'b', 1)]
+    );
+    let widths: Vec<_> = char_width_indices("a\u{4E00}\u{301}", Unicode14).collect();
+    assert_eq!(widths, [(0, 'a', 1), (1, '\u{4E00}', 2), (4, '\u{301}', 0)]);
+}
+
+#[test]
+#[cfg(feature = "emoji")]
+#[cfg(not(feature = "ascii-only"))]
+fn char_width_indices_unicode14() {
+    let s = "a✔️b";
+    let indices: Vec<_> = char_width_indices(s, Unicode14).collect();
+    assert_eq!(
+        indices,
+        [(0, 'a', 1), (1, '✔', 2), (4, '\u{FE0F}', 0), (7, 'b', 1)]
+    );
+    let total: usize = indices.iter().map(|&(_, _, width)| width).sum();
+    assert_eq!(total, str_width(s, Unicode14));
+}
+
+#[test]
+#[cfg(feature = "emoji")]
+fn emoji_presentation() {
+    use crate::{resolve_presentation, Presentation};
+
+    assert_eq!(
+        resolve_presentation('✔', "\u{FE0F}"),
+        Some(Presentation::Emoji)
+    );
+    assert_eq!(
+        resolve_presentation('✔', "\u{FE0E}"),
+        Some(Presentation::Text)
+    );
+    assert_eq!(resolve_presentation('✔', ""), Some(Presentation::Text));
+    assert_eq!(resolve_presentation('✔', "a"), Some(Presentation::Text));
+    // watch has emoji presentation by default
+    assert_eq!(resolve_presentation('⌚', ""), Some(Presentation::Emoji));
+    assert_eq!(
+        resolve_presentation('⌚', "\u{FE0E}"),
+        Some(Presentation::Text)
+    );
+    // raised fist can not be switched to text presentation
+    assert_eq!(
+        resolve_presentation('✊', "\u{FE0E}"),
+        Some(Presentation::Emoji)
+    );
+    assert_eq!(resolve_presentation('a', "\u{FE0F}"), None);
+    assert_eq!(resolve_presentation('\u{4E00}', ""), None);
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn hangul_jamo() {
+    for compat in [Unicode9, Unicode14] {
+        // leading consonants are wide
+        assert_eq!(str_width("\u{1100}", compat), 2);
+        assert_eq!(str_width("\u{115F}", compat), 2);
+        // vowels and trailing consonants combine with the leading consonant
+        assert_eq!(str_width("\u{1161}", compat), 0);
+        assert_eq!(str_width("\u{11A7}", compat), 0);
+        assert_eq!(str_width("\u{11A8}", compat), 0);
+        assert_eq!(str_width("\u{11FF}", compat), 0);
+        // decomposed and precomposed syllables have the same width
+        assert_eq!(str_width("\u{1112}\u{1161}", compat), 2);
+        assert_eq!(str_width("\u{1112}\u{1161}\u{11AB}", compat), 2);
+        assert_eq!(str_width("\u{D55C}", compat), 2);
+        assert_eq!(str_width("\u{D55C}\u{AE00}", compat), 4);
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn width_cache() {
+    use crate::WidthCache;
+
+    let labels = ["name", "\u{4E00}\u{4E01}", "✔️", "", "👩‍❤️‍👨"];
+    for compat in [Unicode9, Unicode14] {
+        let mut cache = WidthCache::new(compat);
+        for _ in 0..2 {
+            for label in labels {
+                assert_eq!(cache.width(label), str_width(label, compat));
+            }
+        }
+        // strings are compared by content, not by their hash or address
+        let mut label = String::from("abc");
+        assert_eq!(cache.width(&label), 3);
+        label.push('\u{4E00}');
+        assert_eq!(cache.width(&label), 3 + str_width("\u{4E00}", compat));
+        assert_eq!(cache.width(&label[..3]), 3);
+        cache.clear();
+        assert_eq!(cache.width("abc"), 3);
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn single_width_cache() {
+    for compat in [Unicode9, Unicode14] {
+        let mut cache = SingleWidthCache::new(compat);
+        let mut label = String::from("frame 你好");
+        assert!(!cache.is_cached(&label));
+        assert_eq!(cache.width(&label), 10);
+        assert!(cache.is_cached(&label));
+        assert_eq!(cache.width(&label), 10);
+        // a different slice misses, even with the same content
+        let copy = label.clone();
+        assert!(!cache.is_cached(&copy));
+        assert!(!cache.is_cached(&label[..5]));
+        label.push('!');
+        assert!(!cache.is_cached(&label));
+        assert_eq!(cache.width(&label), 11);
+        assert!(cache.is_cached(&label));
+        cache.clear();
+        assert!(!cache.is_cached(&label));
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn width_until_cursor() {
+    let s = "a\u{4E00}b";
+    for compat in [Unicode9, Unicode14] {
+        assert_eq!(width_until_byte(s, 0, compat), 0);
+        // cursor on the wide char
+        assert_eq!(width_until_byte(s, 1, compat), 1);
+        // cursor after the wide char
+        assert_eq!(width_until_byte(s, 4, compat), 3);
+        assert_eq!(width_until_byte(s, s.len(), compat), 4);
+    }
+}
+
+#[test]
+#[cfg(feature = "emoji")]
+#[cfg(not(feature = "ascii-only"))]
+fn width_until_variation_selector() {
+    let s = "✔️";
+    assert_eq!(width_until_byte(s, 3, Unicode14), 1);
+    assert_eq!(width_until_byte(s, s.len(), Unicode14), 2);
+    assert_eq!(width_until_byte(s, s.len(), Unicode9), 1);
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[cfg(not(feature = "ascii-only"))]
+fn os_str() {
+    use crate::os_str_width;
+    use std::ffi::OsStr;
+
+    let path = OsStr::new("docs/\u{4E00}.txt");
+    assert_eq!(os_str_width(path, Unicode9), 11);
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+
+        let path = OsStr::from_bytes(b"docs/a\xff\xfeb.txt");
+        assert_eq!(os_str_width(path, Unicode9), 13);
+        assert_eq!(os_str_width(path, Unicode14), 13);
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn bytes_with_replacement() {
+    for compat in [Unicode9, Unicode14] {
+        assert_eq!(bytes_width(b"", compat), 0);
+        assert_eq!(bytes_width("a\u{4E00}b".as_bytes(), compat), 4);
+        assert_eq!(bytes_width(b"\xff\xfe\x80", compat), 3);
+        assert_eq!(bytes_width(b"a\xffb", compat), 3);
+        // incomplete sequence at the end of the input
+        assert_eq!(bytes_width(b"a\xe4\xb8", compat), 2);
+        // a truncated sequence followed by a valid multibyte character
+        assert_eq!(bytes_width(b"\xe4\xb8\xe4\xb8\x80", compat), 3);
+        assert_eq!(bytes_width(b"\xe4\xb8\x80\xb8\x80", compat), 4);
+    }
+}
+
+#[test]
+fn replacement_width() {
+    use crate::bytes_width_with;
+
+    let narrow = WidthOptions::new(Unicode9).replacement_width(1);
+    let wide = WidthOptions::new(Unicode9).replacement_width(2);
+    assert_eq!(bytes_width_with(b"a\xffb", WidthOptions::new(Unicode9)), 3);
+    assert_eq!(bytes_width_with(b"a\xffb", narrow), 3);
+    assert_eq!(bytes_width_with(b"a\xffb", wide), 4);
+    assert_eq!(bytes_width_with(b"\xff\xfe\x80", wide), 6);
+    // replacement characters that are part of valid input are not affected
+    assert_eq!(bytes_width_with("\u{FFFD}".as_bytes(), wide), 1);
+    #[cfg(all(unix, feature = "std"))]
+    {
+        use crate::os_str_width_with;
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let path = OsStr::from_bytes(b"a\xffb");
+        assert_eq!(os_str_width_with(path, narrow), 3);
+        assert_eq!(os_str_width_with(path, wide), 4);
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn pad_to_width_alignment() {
+    assert_eq!(pad_to_width("ab", 5, Align::Left, Unicode9), "ab   ");
+    assert_eq!(pad_to_width("ab", 5, Align::Right, Unicode9), "   ab");
+    assert_eq!(pad_to_width("ab", 5, Align::Center, Unicode9), " ab  ");
+    assert_eq!(pad_to_width("你", 3, Align::Left, Unicode9), "你 ");
+    assert_eq!(pad_to_width("abc", 2, Align::Left, Unicode9), "abc");
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn pad_to_width_cow_borrows() {
+    let padded = pad_to_width_cow("你好", 4, Align::Left, Unicode9);
+    assert!(matches!(padded, Cow::Borrowed("你好")));
+    let padded = pad_to_width_cow("abcdef", 4, Align::Right, Unicode9);
+    assert!(matches!(padded, Cow::Borrowed("abcdef")));
+    let padded = pad_to_width_cow("你", 4, Align::Right, Unicode9);
+    assert!(matches!(&padded, Cow::Owned(s) if s == "  你"));
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn column_range_wide_char() {
+    let s = "a你b";
+    assert_eq!(column_range(s, 1..4, Unicode9), 1..3);
+    assert_eq!(column_range(s, 0..1, Unicode9), 0..1);
+    assert_eq!(column_range(s, 4..5, Unicode9), 3..4);
+    assert_eq!(column_range(s, 2..3, Unicode9), 1..3);
+    assert_eq!(column_range(s, 4..4, Unicode9), 3..3);
+}
+
+#[test]
+#[cfg(feature = "emoji")]
+#[cfg(not(feature = "ascii-only"))]
+fn column_range_variation_selector() {
+    // '✔' (3 bytes) followed by VS16 (3 bytes)
+    let s = "a✔\u{FE0F}b";
+    assert_eq!(column_range(s, 1..7, Unicode14), 1..3);
+    assert_eq!(column_range(s, 1..4, Unicode14), 1..3);
+    assert_eq!(column_range(s, 4..8, Unicode14), 1..4);
+    assert_eq!(column_range(s, 0..8, Unicode14), 0..4);
+    assert_eq!(column_range(s, 7..8, Unicode14), 3..4);
+    assert_eq!(column_range(s, 1..7, Unicode9), 1..2);
+}
+
+/// `lookup_width` indexes the tables with bit-shifted codepoints,
+/// make sure it can never index out of bounds.
+#[test]
+fn all_chars_in_bounds() {
+    for c in (0..=char::MAX as u32).filter_map(char::from_u32) {
+        assert!(char_width_unicode9(c) <= 2, "{c:?}");
+        assert!(char_width_unicode14(c, "") <= 2, "{c:?}");
+        assert!(char_width_unicode14(c, "\u{FE0E}") <= 2, "{c:?}");
+        assert!(char_width_unicode14(c, "\u{FE0F}") <= 2, "{c:?}");
+    }
+}
+
+#[test]
+fn width_conversions() {
+    assert_eq!(u8::from(Width::Zero), 0);
+    assert_eq!(u8::from(Width::One), 1);
+    assert_eq!(u8::from(Width::Two), 2);
+    assert_eq!(usize::from(Width::Two), 2);
+    assert_eq!(Width::try_from(0), Ok(Width::Zero));
+    assert_eq!(Width::try_from(1), Ok(Width::One));
+    assert_eq!(Width::try_from(2), Ok(Width::Two));
+    let err: InvalidWidth = Width::try_from(3).unwrap_err();
+    assert_eq!(err.value(), 3);
+    assert_eq!(err.to_string(), "invalid width 3, must be 0, 1 or 2");
+}
+
+#[test]
+fn pad_to_width_rtl() {
+    // "مرحبا" (hello) has five single width characters
+    let arabic = "مرحبا";
+    assert_eq!(
+        pad_to_width_directed(arabic, 7, Align::Start, Direction::Rtl, Unicode9),
+        "  مرحبا"
+    );
+    assert_eq!(
+        pad_to_width_directed(arabic, 7, Align::End, Direction::Rtl, Unicode9),
+        "مرحبا  "
+    );
+    assert_eq!(
+        pad_to_width_directed(arabic, 7, Align::Left, Direction::Rtl, Unicode9),
+        "مرحبا  "
+    );
+    assert_eq!(
+        pad_to_width_directed("ab", 4, Align::Start, Direction::Ltr, Unicode9),
+        "ab  "
+    );
+    assert_eq!(pad_to_width("ab", 4, Align::End, Unicode9), "  ab");
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn byte_order_mark() {
+    assert_eq!(str_width("\u{FEFF}abc", Unicode9), 3);
+    assert_eq!(str_width("\u{FEFF}abc", Unicode14), 3);
+    assert_eq!(str_width("ab\u{FEFF}c", Unicode9), 3);
+    assert_eq!(str_width("ab\u{FEFF}c", Unicode14), 3);
+}
+
+#[test]
+fn terminal_profile_unicode_compat() {
+    assert_eq!(TerminalProfile::Kitty.unicode_compat(), Unicode14);
+    assert_eq!(TerminalProfile::WindowsTerminal.unicode_compat(), Unicode14);
+    assert_eq!(TerminalProfile::WezTerm.unicode_compat(), Unicode9);
+    assert_eq!(TerminalProfile::Alacritty.unicode_compat(), Unicode9);
+    assert_eq!(TerminalProfile::Konsole.unicode_compat(), Unicode9);
+    assert_eq!(TerminalProfile::Xterm.unicode_compat(), Unicode9);
+    assert_eq!(TerminalProfile::Mintty.unicode_compat(), Unicode9);
+    assert_eq!(TerminalProfile::Putty.unicode_compat(), Unicode9);
+    assert_eq!(TerminalProfile::Generic.unicode_compat(), Unicode9);
+    assert_eq!(
+        TerminalProfile::default().unicode_compat(),
+        UnicodeCompat::default()
+    );
+}
+
+#[test]
+fn terminal_profile_from_name() {
+    let profiles = [
+        ("mintty", TerminalProfile::Mintty, Unicode9),
+        ("PuTTY", TerminalProfile::Putty, Unicode9),
+        ("xterm", TerminalProfile::Xterm, Unicode9),
+        ("kitty", TerminalProfile::Kitty, Unicode14),
+        ("WezTerm", TerminalProfile::WezTerm, Unicode9),
+        ("alacritty", TerminalProfile::Alacritty, Unicode9),
+        ("konsole", TerminalProfile::Konsole, Unicode9),
+        (
+            "windows-terminal",
+            TerminalProfile::WindowsTerminal,
+            Unicode14,
+        ),
+        ("generic", TerminalProfile::Generic, Unicode9),
+    ];
+    for (name, profile, compat) in profiles {
+        assert_eq!(TerminalProfile::from_name(name), Some(profile), "{name}");
+        assert_eq!(profile.unicode_compat(), compat, "{name}");
+    }
+    assert_eq!(
+        TerminalProfile::from_name("MINTTY"),
+        Some(TerminalProfile::Mintty)
+    );
+    assert_eq!(TerminalProfile::from_name("iterm"), None);
+    assert_eq!(TerminalProfile::from_name(""), None);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn detect_terminal() {
+    use std::ffi::OsString;
+
+    fn detect(vars: &[(&str, &str)]) -> TerminalProfile {
+        TerminalProfile::detect_from(|name| {
+            vars.iter()
+                .find(|&&(var, _)| var == name)
+                .map(|&(_, value)| OsString::from(value))
+        })
+    }
+
+    assert_eq!(detect(&[("TERM", "xterm-kitty")]), TerminalProfile::Kitty);
+    assert_eq!(
+        detect(&[("TERM", "xterm-kitty")]).unicode_compat(),
+        Unicode14
+    );
+    assert_eq!(detect(&[("TERM", "alacritty")]), TerminalProfile::Alacritty);
+    assert_eq!(detect(&[("TERM", "alacritty")]).unicode_compat(), Unicode9);
+    assert_eq!(
+        detect(&[("TERM_PROGRAM", "WezTerm"), ("TERM", "xterm-256color")]),
+        TerminalProfile::WezTerm
+    );
+    let windows_terminal = [
+        ("WT_SESSION", "5b9c6f43-2bb3-4ad5-9e1c-4a9d5f0b3e2a"),
+        ("TERM_PROGRAM", "WezTerm"),
+        ("TERM", "xterm-256color"),
+    ];
+    assert_eq!(detect(&windows_terminal), TerminalProfile::WindowsTerminal);
+    assert_eq!(detect(&windows_terminal).unicode_compat(), Unicode14);
+    assert_eq!(
+        detect(&[("TERM_PROGRAM", "mintty"), ("TERM", "xterm-256color")]),
+        TerminalProfile::Mintty
+    );
+    assert_eq!(
+        detect(&[("TERM", "putty-256color")]),
+        TerminalProfile::Putty
+    );
+    assert_eq!(detect(&[("TERM", "dumb")]), TerminalProfile::Generic);
+    assert_eq!(detect(&[]), TerminalProfile::Generic);
+    assert_eq!(detect(&[]).unicode_compat(), Unicode9);
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn wrap_lines() {
+    assert_eq!(wrap("abcdefg", 3, Unicode9), ["abc", "def", "g"]);
+    assert_eq!(wrap("ab你好", 3, Unicode9), ["ab", "你", "好"]);
+    assert_eq!(
+        wrap("ab\ncd\r\n\nefgh", 3, Unicode9),
+        ["ab", "cd", "", "efg", "h"]
+    );
+    assert_eq!(
+        wrap("e\u{301}e\u{301}", 1, Unicode9),
+        ["e\u{301}", "e\u{301}"]
+    );
+    assert!(wrap("", 3, Unicode9).is_empty());
+    // a single grapheme wider than the maximum width
+    assert_eq!(wrap("a你b", 1, Unicode9), ["a", "你", "b"]);
+    assert_eq!(wrap("你好", 0, Unicode9), ["你", "好"]);
+}
+
+#[test]
+fn wrap_iter_matches_wrap() {
+    for (s, max_width) in [
+        ("abcdefg", 3),
+        ("ab你好c", 3),
+        ("你好", 1),
+        ("hello\nworld\n", 4),
+        ("🇩🇪🇫🇷 flags", 2),
+    ] {
+        let lines: Vec<_> = wrap_iter(s, max_width, Unicode9).collect();
+        assert_eq!(lines, wrap(s, max_width, Unicode9));
+    }
+    let mut lines = wrap_iter("abcdef", 2, Unicode9);
+    assert_eq!(lines.next(), Some("ab"));
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn rev_iter() {
+    for s in [
+        "✔\u{FE0F}",
+        "✔\u{FE0E}",
+        "a✔b",
+        "#\u{FE0F}\u{20E3}",
+        "你好\u{FE0F}",
+        "\u{FE0F}✔",
+    ] {
+        assert_eq!(
+            rev_iter_width(s.chars().rev(), Unicode9),
+            str_width(s, Unicode9),
+            "{s}"
+        );
+        assert_eq!(
+            rev_iter_width(s.chars().rev(), Unicode14),
+            str_width(s, Unicode14),
+            "{s}"
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "emoji")]
+#[cfg(not(feature = "ascii-only"))]
+fn rev_iter_variation_selector() {
+    assert_eq!(rev_iter_width("✔\u{FE0F}".chars().rev(), Unicode9), 1);
+    assert_eq!(rev_iter_width("✔\u{FE0F}".chars().rev(), Unicode14), 2);
+    assert_eq!(rev_iter_width("✔\u{FE0F}a".chars().rev(), Unicode14), 3);
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn combining_marks() {
+    for compat in [Unicode9, Unicode14] {
+        assert_eq!(str_width("a\u{0301}\u{0302}\u{0303}", compat), 1);
+        assert_eq!(grapheme_width("a\u{0301}\u{0302}\u{0303}", compat), 1);
+        assert_eq!(str_width("你\u{0301}\u{0302}", compat), 2);
+        // a lone combining mark without a base is zero width
+        assert_eq!(str_width("\u{0301}a", compat), 1);
+        assert_eq!(str_width("\u{0301}\u{0302}", compat), 0);
+    }
+}
+
+/// A combining mark after a wide base must not inflate the width of the cluster.
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn combining_mark_after_wide_base() {
+    for compat in [Unicode9, Unicode14] {
+        assert_eq!(str_width("甲\u{0301}", compat), 2);
+        assert_eq!(grapheme_width("甲\u{0301}", compat), 2);
+        assert_eq!(str_width("甲\u{0301}乙", compat), 4);
+        assert_eq!(str_width("\u{1F600}\u{0301}", compat), 2);
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn combining_marks_for_symbols() {
+    for compat in [Unicode9, Unicode14] {
+        // all assigned characters of the block are combining marks (Mn or Me)
+        for c in '\u{20D0}'..='\u{20F0}' {
+            assert_eq!(str_width(c.encode_utf8(&mut [0; 4]), compat), 0, "{c:?}");
+        }
+        // COMBINING ENCLOSING CIRCLE and COMBINING ENCLOSING KEYCAP
+        assert_eq!(str_width("\u{20DD}", compat), 0);
+        assert_eq!(str_width("\u{20E3}", compat), 0);
+        assert_eq!(str_width("a\u{20DD}", compat), 1);
+        assert_eq!(str_width("你\u{20DD}", compat), 2);
+        assert_eq!(str_width("1\u{20E3}", compat), 1);
+        assert_eq!(grapheme_width("\u{2192}\u{20D7}", compat), 1);
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn enclosed_cjk_letters_and_months() {
+    for compat in [Unicode9, Unicode14] {
+        // circled number forty eight
+        assert_eq!(str_width("\u{32BD}", compat), 2);
+        // circled number twenty one and fifty
+        assert_eq!(str_width("\u{3251}\u{32BF}", compat), 4);
+        // parenthesized hangul kiyeok, circled ideograph one
+        assert_eq!(str_width("\u{3200}\u{3280}", compat), 4);
+        // square era name reiwa
+        assert_eq!(str_width("\u{32FF}", compat), 2);
+        // the circled numbers on black squares are ambiguous, not wide
+        assert_eq!(str_width("\u{3248}", compat), 1);
+        assert_eq!(str_width_cjk("\u{3248}", compat), 2);
+    }
+}
+
+/// Regional indicators have `Emoji_Presentation` so a single regional indicator is
+/// double width. A flag is a pair of regional indicators that forms a single
+/// grapheme cluster and is therefore also double width, not four columns wide.
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn regional_indicator_width() {
+    for c in '\u{1F1E6}'..='\u{1F1FF}' {
+        assert_eq!(packed_width(c), 2, "{c:?}");
+        assert_eq!(char_width_unicode9(c), 2, "{c:?}");
+        assert_eq!(char_width_unicode14(c, ""), 2, "{c:?}");
+    }
+    let flag = "\u{1F1E9}\u{1F1EA}";
+    for compat in [Unicode9, Unicode14] {
+        assert_eq!(grapheme_width("\u{1F1E6}", compat), 2);
+        assert_eq!(grapheme_width(flag, compat), 2);
+        assert_eq!(next_grapheme_width(flag, compat), Some((2, 8)));
+        assert_eq!(last_grapheme_width(flag, compat), Some((2, 8)));
+        // a third regional indicator starts a new (unpaired) grapheme
+        assert_eq!(
+            next_grapheme_width("\u{1F1E9}\u{1F1EA}\u{1F1EB}", compat),
+            Some((2, 8))
+        );
+        assert_eq!(
+            last_grapheme_width("\u{1F1E9}\u{1F1EA}\u{1F1EB}", compat),
+            Some((2, 4))
+        );
+        // str_width measures characters, not graphemes
+        assert_eq!(str_width(flag, compat), 4);
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn ideographic_description_characters() {
+    for compat in [Unicode9, Unicode14] {
+        // IDEOGRAPHIC DESCRIPTION CHARACTER LEFT TO RIGHT
+        assert_eq!(str_width("\u{2FF0}", compat), 2);
+        // IDEOGRAPHIC DESCRIPTION CHARACTER OVERLAID
+        assert_eq!(str_width("\u{2FFB}", compat), 2);
+        // a composition description of 好
+        assert_eq!(str_width("\u{2FF0}女子", compat), 6);
+        for c in '\u{2FF0}'..='\u{2FFB}' {
+            assert_eq!(str_width(c.encode_utf8(&mut [0; 4]), compat), 2, "{c:?}");
+        }
+    }
+}
+
+#[test]
+fn counting_rod_numerals() {
+    // counting rod numerals and tally marks are east asian neutral
+    for compat in [Unicode9, Unicode14] {
+        for s in [
+            "\u{1D360}",
+            "\u{1D369}",
+            "\u{1D371}",
+            "\u{1D372}",
+            "\u{1D377}",
+        ] {
+            assert_eq!(str_width(s, compat), 1, "{s:?}");
+            assert_eq!(str_width_cjk(s, compat), 1, "{s:?}");
+        }
+        // ideographic tally mark five
+        assert_eq!(str_width("\u{1D378}\u{1D378}\u{1D376}", compat), 3);
+    }
+    assert_eq!(char_width_unicode9('\u{1D378}'), 1);
+}
+
+#[test]
+#[cfg(feature = "emoji")]
+fn emoji_variation_sequences() {
+    use crate::emoji_variation_codepoints;
+
+    let variations: Vec<char> = emoji_variation_codepoints().collect();
+    // emoji-variation-sequences.txt of unicode 15.0 lists 354 characters
+    assert_eq!(variations.len(), 354);
+    for c in ['✔', '☺', '⌚', '#', '1', '\u{1F170}'] {
+        assert!(variations.contains(&c), "{c:?}");
+    }
+    for c in ['a', '你', '\u{1F600}'] {
+        assert!(!variations.contains(&c), "{c:?}");
+    }
+    assert!(variations.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn ideographic_variation_sequences() {
+    for compat in [Unicode9, Unicode14] {
+        assert_eq!(str_width("\u{4E00}\u{E0100}", compat), 2);
+        assert_eq!(grapheme_width("\u{4E00}\u{E0100}", compat), 2);
+        assert_eq!(
+            next_grapheme_width("\u{8FBB}\u{E0101}a", compat),
+            Some((2, 7))
+        );
+        // the whole variation selectors supplement is zero width
+        for c in '\u{E0100}'..='\u{E01EF}' {
+            assert_eq!(str_width(c.encode_utf8(&mut [0; 4]), compat), 0, "{c:?}");
+        }
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn codepoints_of_width() {
+    let wide: Vec<char> = codepoints_with_width(2, Unicode9).collect();
+    for c in ['你', '\u{3000}', '\u{1F600}', '\u{20000}'] {
+        assert!(wide.contains(&c), "{c:?}");
+    }
+    for c in ['a', '\u{00E9}', '\u{0301}', '\u{2714}'] {
+        assert!(!wide.contains(&c), "{c:?}");
+    }
+    assert!(codepoints_with_width(0, Unicode14).any(|c| c == '\u{0301}'));
+    assert!(codepoints_with_width(1, Unicode14).any(|c| c == 'a'));
+    assert_eq!(codepoints_with_width(3, Unicode9).count(), 0);
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn cjk_variation_selectors() {
+    for compat in [Unicode9, Unicode14] {
+        assert_eq!(str_width("\u{4E00}\u{FE00}", compat), 2);
+        assert_eq!(grapheme_width("\u{4E00}\u{FE00}", compat), 2);
+        for vs in '\u{FE00}'..='\u{FE0D}' {
+            assert_eq!(str_width(vs.encode_utf8(&mut [0; 4]), compat), 0);
+        }
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn take_width_budget() {
+    assert_eq!(take_width("abc", 5, Unicode9), (3, 3));
+    assert_eq!(take_width("abcdef", 4, Unicode9), (4, 4));
+    // the wide char does not fit into the last column
+    assert_eq!(take_width("ab你", 3, Unicode9), (2, 2));
+    assert_eq!(take_width("你好", 3, Unicode9), (2, 3));
+    assert_eq!(take_width("你", 1, Unicode9), (0, 0));
+    // combining marks are never split from their base
+    assert_eq!(take_width("ae\u{301}b", 2, Unicode9), (2, 4));
+    assert_eq!(truncate_to_width("ab你", 3, Unicode9), "ab");
+    assert_eq!(truncate_to_width("ab你", 4, Unicode9), "ab你");
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn arabic_format_marks() {
+    for compat in [Unicode9, Unicode14] {
+        for c in [
+            '\u{0600}', '\u{0601}', '\u{0602}', '\u{0603}', '\u{0604}', '\u{0605}', '\u{06DD}',
+            '\u{070F}', '\u{08E2}',
+        ] {
+            assert_eq!(char_width_unicode9(c), 0, "{c:?}");
+            assert_eq!(str_width(c.encode_utf8(&mut [0; 4]), compat), 0, "{c:?}");
+        }
+        // ARABIC NUMBER SIGN followed by the digits 123
+        assert_eq!(str_width("\u{0600}\u{0661}\u{0662}\u{0663}", compat), 3);
+        // END OF AYAH enclosing the verse number 12
+        assert_eq!(str_width("سلام \u{06DD}\u{0661}\u{0662}", compat), 7);
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn fullwidth_halfwidth_forms() {
+    for compat in [Unicode9, Unicode14] {
+        // FULLWIDTH LATIN CAPITAL LETTER A
+        assert_eq!(str_width("\u{FF21}", compat), 2);
+        // HALFWIDTH KATAKANA LETTER A
+        assert_eq!(str_width("\u{FF71}", compat), 1);
+        for c in '\u{FF01}'..='\u{FF60}' {
+            assert_eq!(char_width_unicode9(c), 2, "{c:?}");
+        }
+        for c in '\u{FF61}'..='\u{FFDC}' {
+            assert_eq!(char_width_unicode9(c), 1, "{c:?}");
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "segmentation")]
+fn segmented_width() {
+    use crate::str_width_segmented;
+
+    let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+    let flag = "\u{1F1E9}\u{1F1EA}";
+    for compat in [Unicode9, Unicode14] {
+        assert_eq!(str_width_segmented(family, compat), 2);
+        assert_eq!(str_width_segmented(flag, compat), 2);
+        assert_eq!(str_width_segmented("a\u{1F44B}\u{1F3FD}b", compat), 4);
+        assert_eq!(str_width_segmented("e\u{301}你", compat), 3);
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn raw_table_lookup() {
+    assert_eq!(packed_width('a'), 1);
+    assert_eq!(packed_width('甲'), 2);
+    assert_eq!(packed_width('\u{0301}'), 0);
+    assert_eq!(packed_width('\0'), 0);
+    for c in (0..=char::MAX as u32).filter_map(char::from_u32) {
+        if !c.is_ascii() {
+            assert_eq!(usize::from(packed_width(c)), char_width_unicode9(c));
+        }
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn brahmic_combining_marks() {
+    for compat in [Unicode9, Unicode14] {
+        // KA + VIRAMA + SSA (conjunct kssa)
+        assert_eq!(str_width("\u{0915}\u{094D}\u{0937}", compat), 2);
+        // "namaste": vowel signs and the virama are zero width
+        assert_eq!(
+            str_width("\u{0928}\u{092E}\u{0938}\u{094D}\u{0924}\u{0947}", compat),
+            4
+        );
+        // Bengali "bangla": the vowel sign AA (Mc, but Grapheme_Cluster_Break=Extend)
+        // is zero width while the anusvara is a spacing mark
+        assert_eq!(
+            str_width("\u{09AC}\u{09BE}\u{0982}\u{09B2}\u{09BE}", compat),
+            3
+        );
+        // "tamil": the spacing vowel sign I occupies a cell, the virama is zero width
+        assert_eq!(
+            str_width("\u{0BA4}\u{0BAE}\u{0BBF}\u{0BB4}\u{0BCD}", compat),
+            4
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "emoji")]
+#[cfg(not(feature = "ascii-only"))]
+fn rgi_zwj_sequences() {
+    let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+    let rainbow_flag = "\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}";
+    // cat + ZWJ + rocket is not an RGI sequence
+    let made_up = "\u{1F431}\u{200D}\u{1F680}";
+    let options = WidthOptions::new(Unicode9);
+    assert_eq!(grapheme_width_with(made_up, options), 2);
+    let options = options.split_unknown_zwj_sequences(true);
+    assert_eq!(grapheme_width_with(family, options), 2);
+    assert_eq!(grapheme_width_with(made_up, options), 4);
+    // the generated list contains all RGI sequences of emoji 15.0, like
+    // professions with skin tones, kissing couples and the black bird added in 15.0
+    assert_eq!(crate::emoji_zwj_sequences::EMOJI_ZWJ_SEQUENCES.len(), 1350);
+    let woman_technologist = "\u{1F469}\u{1F3FD}\u{200D}\u{1F4BB}";
+    let kiss =
+        "\u{1F9D1}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FC}";
+    let black_bird = "\u{1F426}\u{200D}\u{2B1B}";
+    for sequence in [woman_technologist, kiss, black_bird] {
+        assert_eq!(grapheme_width_with(sequence, options), 2, "{sequence:?}");
+    }
+    for compat in [Unicode9, Unicode14] {
+        let options = WidthOptions::new(compat).split_unknown_zwj_sequences(true);
+        assert_eq!(grapheme_width_with(rainbow_flag, options), 2);
+        // VS16 is optional
+        assert_eq!(
+            grapheme_width_with("\u{1F3F3}\u{200D}\u{1F308}", options),
+            2
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[cfg(not(feature = "ascii-only"))]
+fn lines_widths_reader() {
+    use crate::lines_widths;
+    use std::io::Cursor;
+
+    let reader = Cursor::new("hello\n你好\r\n\na\u{301}b");
+    let widths: Vec<usize> = lines_widths(reader, Unicode9)
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(widths, [5, 4, 0, 2]);
+
+    let longest = lines_widths(Cursor::new("ab\n你好你\nabcd\n"), Unicode14)
+        .map(Result::unwrap)
+        .max();
+    assert_eq!(longest, Some(6));
+
+    let mut invalid = lines_widths(Cursor::new(b"a\n\xff\n"), Unicode9);
+    assert_eq!(invalid.next().unwrap().unwrap(), 1);
+    assert!(invalid.next().unwrap().is_err());
+}
+
+#[test]
+fn caret_notation_controls() {
+    assert_eq!(caret_notation('\0'), Some("^@"));
+    assert_eq!(caret_notation('\x03'), Some("^C"));
+    assert_eq!(caret_notation('\x1b'), Some("^["));
+    assert_eq!(caret_notation('\x1f'), Some("^_"));
+    assert_eq!(caret_notation('\x7f'), Some("^?"));
+    assert_eq!(caret_notation('a'), None);
+    assert_eq!(caret_notation(' '), None);
+    assert_eq!(caret_notation('\u{85}'), None);
+    let caret = WidthOptions::new(Unicode9).control_policy(ControlPolicy::Caret);
+    for c in ('\0'..='\x1f').chain(['\x7f']) {
+        let notation = caret_notation(c).unwrap();
+        assert_eq!(str_width(notation, Unicode9), 2);
+        assert_eq!(str_width_with(c.encode_utf8(&mut [0; 4]), caret), 2);
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn ambiguous_width() {
+    for compat in [Unicode9, Unicode14] {
+        // WHITE CIRCLE is ambiguous
+        assert_eq!(str_width("a○b", compat), 3);
+        assert_eq!(str_width_cjk("a○b", compat), 4);
+        assert_eq!(str_width_cjk("a\u{301}", compat), 1);
+        assert_eq!(str_width_cjk("αβγ", compat), 6);
+        assert_eq!(str_width_cjk("你好", compat), 4);
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn contextual_ambiguous_width() {
+    for compat in [Unicode9, Unicode14] {
+        // between two latin letters the ambiguous char is narrow
+        assert_eq!(str_width_contextual("a○b", compat), 3);
+        // after a CJK character it is wide
+        assert_eq!(str_width_contextual("你○b", compat), 5);
+        assert_eq!(str_width_contextual("你○○b", compat), 7);
+        assert_eq!(str_width_contextual("你\u{301}○", compat), 4);
+        assert_eq!(str_width_contextual("你a○", compat), 4);
+        assert_eq!(str_width_contextual("○你", compat), 3);
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn fallible_char_width() {
+    for compat in [Unicode9, Unicode14] {
+        assert_eq!(try_char_width('a', compat), Ok(1));
+        assert_eq!(try_char_width('你', compat), Ok(2));
+        assert_eq!(try_char_width('\u{301}', compat), Ok(0));
+        assert_eq!(try_char_width('\n', compat), Err(WidthError::Control));
+        assert_eq!(try_char_width('\x7f', compat), Err(WidthError::Control));
+        assert_eq!(try_char_width('\u{85}', compat), Err(WidthError::Control));
+        // U+0378 is unassigned in all unicode versions so far
+        assert_eq!(
+            try_char_width('\u{378}', compat),
+            Err(WidthError::Unassigned)
+        );
+        assert_eq!(
+            try_char_width('\u{FFFF}', compat),
+            Err(WidthError::Unassigned)
+        );
+        assert_eq!(try_codepoint_width(0x41, compat), Ok(1));
+        assert_eq!(
+            try_codepoint_width(0xD800, compat),
+            Err(WidthError::Surrogate)
+        );
+        assert_eq!(
+            try_codepoint_width(0x110000, compat),
+            Err(WidthError::Unassigned)
+        );
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn width_with_fallback() {
+    let wide_unassigned = |c: char| (c == '\u{378}').then_some(2);
+    let wide_pua = |c: char| matches!(c, '\u{E000}'..='\u{F8FF}').then_some(2);
+    for compat in [Unicode9, Unicode14] {
+        assert_eq!(str_width("a\u{378}b", compat), 3);
+        assert_eq!(
+            str_width_with_fallback("a\u{378}b", wide_unassigned, compat),
+            4
+        );
+        // the fallback is only consulted for characters without a well defined width
+        assert_eq!(
+            str_width_with_fallback("a你\u{301}", |_| Some(7), compat),
+            3
+        );
+        // returning `None` keeps the normal width
+        assert_eq!(
+            str_width_with_fallback("\u{379}\u{E0A0}", wide_unassigned, compat),
+            2
+        );
+        assert_eq!(
+            str_width_with_fallback("\u{E0A0}\u{E0B0}", wide_pua, compat),
+            4
+        );
+        assert_eq!(str_width_with_fallback("\u{F0000}", wide_pua, compat), 1);
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn raw_codepoint_width() {
+    for compat in [Unicode9, Unicode14] {
+        assert_eq!(codepoint_width(0x41, compat), Some(1));
+        assert_eq!(codepoint_width(0x4E00, compat), Some(2));
+        assert_eq!(codepoint_width(0x301, compat), Some(0));
+        assert_eq!(codepoint_width(0x378, compat), Some(1));
+        for surrogate in [0xD800, 0xDBFF, 0xDC00, 0xDFFF] {
+            assert_eq!(codepoint_width(surrogate, compat), None);
+        }
+        assert_eq!(codepoint_width(0x10FFFD, compat), Some(1));
+        assert_eq!(codepoint_width(0x110000, compat), None);
+        assert_eq!(codepoint_width(u32::MAX, compat), None);
+    }
+}
+
+/// The previous `Chars` based implementation of `str_width(s, Unicode14)`
+fn str_width_unicode14_chars(s: &str) -> usize {
+    let mut chars = s.chars();
+    let mut res = 0;
+    while let Some(c) = chars.next() {
+        if c.is_ascii() && !crate::is_keycap_base(c) {
+            res += (!(c as u8).is_ascii_control()) as usize;
+            continue;
+        }
+        if crate::is_emoji_variation(c) {
+            match chars.as_str().as_bytes() {
+                [0xef, 0xb8, 0x8e, ..] => {
+                    chars = chars.as_str()[3..].chars();
+                    res += 1;
+                    continue;
+                }
+                [0xef, 0xb8, 0x8f, ..] => {
+                    chars = chars.as_str()[3..].chars();
+                    res += 2;
+                    continue;
+                }
+                _ => (),
+            }
+        }
+        res += crate::lookup_width(c) as usize;
+    }
+    res
+}
+
+#[test]
+fn unicode14_byte_scan_matches_chars() {
+    let alphabet = [
+        "a",
+        "\n",
+        "1",
+        "#",
+        "é",
+        "\u{301}",
+        "你",
+        "☺",
+        "✔",
+        "👍",
+        "🏽",
+        "\u{FE0E}",
+        "\u{FE0F}",
+        "\u{20E3}",
+        "\u{200D}",
+        "\u{10FFFF}",
+    ];
+    let mut s = String::new();
+    for a in alphabet {
+        for b in alphabet {
+            for c in alphabet {
+                s.clear();
+                s.extend([a, b, c]);
+                assert_eq!(
+                    str_width(&s, Unicode14),
+                    str_width_unicode14_chars(&s),
+                    "{s:?}"
+                );
+            }
+        }
+    }
+    let all: String = ('\0'..=char::MAX).collect();
+    assert_eq!(str_width(&all, Unicode14), str_width_unicode14_chars(&all));
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn pua_width_override() {
+    for compat in [Unicode9, Unicode14] {
+        let options = WidthOptions::new(compat).pua_width(2);
+        // BMP private use area
+        assert_eq!(str_width("\u{E000}", compat), 1);
+        assert_eq!(str_width_with("\u{E000}", options), 2);
+        assert_eq!(str_width_with("a\u{F8FF}b", options), 4);
+        // supplementary private use planes 15 and 16
+        assert_eq!(str_width("\u{F0000}", compat), 1);
+        assert_eq!(str_width_with("\u{F0000}", options), 2);
+        assert_eq!(str_width_with("\u{10FFFD}", options), 2);
+        assert_eq!(grapheme_width_with("\u{F0000}", options), 2);
+        // noncharacters at the end of the planes are not private use
+        assert_eq!(str_width_with("\u{FFFFE}\u{FFFFF}\u{10FFFF}", options), 0);
+        assert_eq!(str_width_with("a", options), 1);
+    }
+}
+
+#[test]
+fn object_replacement_width() {
+    for compat in [Unicode9, Unicode14] {
+        let options = WidthOptions::new(compat);
+        assert_eq!(str_width_with("a\u{FFFC}b", options), 3);
+        let options = options.object_replacement_width(2);
+        assert_eq!(str_width_with("a\u{FFFC}b", options), 4);
+        assert_eq!(str_width_with("\u{FFFC}\u{FFFC}", options), 4);
+        assert_eq!(str_width_with("\u{FFFD}", options), 1);
+        let options = options.object_replacement_width(0);
+        assert_eq!(str_width_with("a\u{FFFC}b", options), 2);
+    }
+}
+
+#[test]
+fn newline_width() {
+    for compat in [Unicode9, Unicode14] {
+        let options = WidthOptions::new(compat);
+        assert_eq!(str_width_with("a\nb", options), 2);
+        let options = options.newline_width(1);
+        assert_eq!(str_width_with("a\nb", options), 3);
+        assert_eq!(str_width_with("a\r\nb\n", options), 4);
+        assert_eq!(grapheme_width_with("\n", options), 1);
+        // the newline width takes precedence over the control policy
+        let options = options.control_policy(ControlPolicy::Caret);
+        assert_eq!(str_width_with("a\nb", options), 3);
+        assert_eq!(str_width_with("a\r\nb", options), 5);
+        // newlines are not counted if measuring stops at the first newline
+        assert_eq!(str_width_with("a\nb", options.stop_at_newline(true)), 1);
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn align_row_cells() {
+    let mut cells = ["a", "甲乙", "xyz"].map(String::from);
+    align_row(&mut cells, Align::Left, Unicode9);
+    assert_eq!(cells, ["a   ", "甲乙", "xyz "]);
+    let mut cells = ["a", "甲乙", "xyz"].map(String::from);
+    align_row(&mut cells, Align::Right, Unicode9);
+    assert_eq!(cells, ["   a", "甲乙", " xyz"]);
+    // cells that already have the same width are unchanged
+    let mut cells = ["ab", "甲"].map(String::from);
+    align_row(&mut cells, Align::Center, Unicode9);
+    assert_eq!(cells, ["ab", "甲"]);
+    align_row(&mut [], Align::Left, Unicode9);
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn pad_into_writer() {
+    for align in [
+        Align::Left,
+        Align::Right,
+        Align::Center,
+        Align::Start,
+        Align::End,
+    ] {
+        for s in ["", "ab", "你好", "abcdef"] {
+            let mut buf = String::from("> ");
+            pad_into(&mut buf, s, 5, align, ' ', Unicode9).unwrap();
+            assert_eq!(buf, format!("> {}", pad_to_width(s, 5, align, Unicode9)));
+        }
+    }
+    let mut buf = String::new();
+    pad_into(&mut buf, "你", 5, Align::Center, '.', Unicode9).unwrap();
+    assert_eq!(buf, ".你..");
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn space_separators() {
+    // all space separators (general category Zs) except the ideographic space
+    let narrow = [
+        ' ', '\u{A0}', '\u{1680}', '\u{2000}', '\u{2001}', '\u{2002}', '\u{2003}', '\u{2004}',
+        '\u{2005}', '\u{2006}', '\u{2007}', '\u{2008}', '\u{2009}', '\u{200A}', '\u{202F}',
+        '\u{205F}',
+    ];
+    for compat in [Unicode9, Unicode14] {
+        for c in narrow {
+            assert_eq!(str_width(c.encode_utf8(&mut [0; 4]), compat), 1, "{c:?}");
+        }
+        assert_eq!(str_width("\u{A0}", compat), 1);
+        assert_eq!(str_width("\u{2009}", compat), 1);
+        assert_eq!(str_width("\u{3000}", compat), 2);
+        // the zero width space is a format character (Cf), not a space separator
+        assert_eq!(str_width("\u{200B}", compat), 0);
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn spacing_modifier_letters() {
+    for compat in [Unicode9, Unicode14] {
+        // MODIFIER LETTER SMALL H + COMBINING ACUTE ACCENT
+        assert_eq!(str_width("\u{02B0}\u{0301}", compat), 1);
+        // IPA transcription of "thin" with aspiration: [tʰɪn]
+        assert_eq!(str_width("[t\u{02B0}\u{026A}n]", compat), 6);
+        // primary stress and length marks combined with a tilde
+        assert_eq!(str_width("\u{02C8}a\u{0303}\u{02D0}", compat), 3);
+        for c in '\u{02B0}'..='\u{02FF}' {
+            assert_eq!(str_width(c.encode_utf8(&mut [0; 4]), compat), 1, "{c:?}");
+        }
+        for c in '\u{0300}'..='\u{036F}' {
+            assert_eq!(str_width(c.encode_utf8(&mut [0; 4]), compat), 0, "{c:?}");
+        }
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn interlinear_annotations() {
+    for compat in [Unicode9, Unicode14] {
+        // INTERLINEAR ANNOTATION ANCHOR, SEPARATOR and TERMINATOR are format characters (Cf)
+        for c in ['\u{FFF9}', '\u{FFFA}', '\u{FFFB}'] {
+            assert_eq!(str_width(c.encode_utf8(&mut [0; 4]), compat), 0, "{c:?}");
+        }
+        // OBJECT REPLACEMENT CHARACTER is a symbol (So)
+        assert_eq!(str_width("\u{FFFC}", compat), 1);
+        // annotated ruby text: only the base and the annotation are visible
+        assert_eq!(str_width("\u{FFF9}漢\u{FFFA}kan\u{FFFB}", compat), 5);
+    }
+    assert_eq!(char_width_unicode9('\u{FFF9}'), 0);
+    assert_eq!(char_width_unicode9('\u{FFFC}'), 1);
+}
+
+#[test]
+fn legacy_computing_symbols() {
+    for compat in [Unicode9, Unicode14] {
+        // BLOCK SEXTANT-1 and BLOCK SEXTANT-23456
+        assert_eq!(str_width("\u{1FB00}", compat), 1);
+        assert_eq!(str_width("\u{1FB3B}", compat), 1);
+        // LOWER LEFT BLOCK DIAGONAL, UPPER ONE EIGHTH BLOCK and CHECKER BOARD FILL
+        assert_eq!(str_width("\u{1FB3C}\u{1FB76}\u{1FB95}", compat), 3);
+        // SEGMENTED DIGIT ZERO
+        assert_eq!(str_width("\u{1FBF0}", compat), 1);
+        // the whole block is single width, including unassigned codepoints
+        for c in '\u{1FB00}'..='\u{1FBFF}' {
+            assert_eq!(str_width(c.encode_utf8(&mut [0; 4]), compat), 1, "{c:?}");
+        }
+    }
+}
+
+/// The symbols for legacy computing supplement were added in unicode 16. The
+/// tables are generated from UCD 15.0 so the block is still unassigned, but
+/// unassigned codepoints are single width which already matches unicode 16.
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn legacy_computing_supplement() {
+    for compat in [Unicode9, Unicode14] {
+        // LEFT HALF RIGHT ONE QUARTER BLOCK and OUTLINED LATIN CAPITAL LETTER A
+        assert_eq!(str_width("\u{1CC00}\u{1CCD6}", compat), 2);
+        // LARGE TYPE PIECE UPPER LEFT ARC
+        assert_eq!(str_width("\u{1CE1A}", compat), 1);
+        for c in '\u{1CC00}'..='\u{1CEBF}' {
+            assert_eq!(str_width(c.encode_utf8(&mut [0; 4]), compat), 1, "{c:?}");
+        }
+        assert_eq!(
+            try_char_width('\u{1CC00}', compat),
+            Err(WidthError::Unassigned)
+        );
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn width_deltas() {
+    for compat in [Unicode9, Unicode14] {
+        assert_eq!(width_delta("你好", "ab", compat), -2);
+        assert_eq!(width_delta("ab", "你好", compat), 2);
+        assert_eq!(width_delta("abc", "你\u{301}a", compat), 0);
+        assert_eq!(width_delta("", "", compat), 0);
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn width_until_stop_char() {
+    for compat in [Unicode9, Unicode14] {
+        assert_eq!(width_until("abc|def", |c| c == '|', compat), 3);
+        assert_eq!(
+            width_until("abc|def", |c| matches!(c, ';' | '|'), compat),
+            3
+        );
+        assert_eq!(width_until("你好|def", |c| c == '|', compat), 4);
+        assert_eq!(
+            width_until("ab你cd", |c| char_width_unicode9(c) == 2, compat),
+            2
+        );
+        assert_eq!(width_until("abcdef", |c| c == '|', compat), 6);
+        assert_eq!(width_until("|abc", |c| c == '|', compat), 0);
+        assert_eq!(width_until("", |c| c == '|', compat), 0);
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn zwj_is_zero_width() {
+    for compat in [Unicode9, Unicode14] {
+        assert_eq!(str_width("a\u{200D}b", compat), 2);
+        assert_eq!(str_width("\u{200D}", compat), 0);
+        assert_eq!(str_width("你\u{200D}好", compat), 4);
+        // str_width does not collapse emoji ZWJ sequences
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        assert_eq!(str_width(family, compat), 6);
+        assert_eq!(grapheme_width(family, compat), 2);
+    }
+    assert_eq!(char_width_unicode9('\u{200D}'), 0);
+    assert_eq!(packed_width('\u{200D}'), 0);
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn trimmed_widths() {
+    for compat in [Unicode9, Unicode14] {
+        assert_eq!(trimmed_width("abc   ", compat), 3);
+        assert_eq!(trimmed_width("   ", compat), 0);
+        assert_eq!(trimmed_width("", compat), 0);
+        assert_eq!(trimmed_width("  a b\t\n", compat), 5);
+        assert_eq!(trimmed_width("你好\u{3000}\u{2028}", compat), 4);
+        // no-break spaces are content and not trimmed
+        assert_eq!(trimmed_width("a ", compat), 1);
+        assert_eq!(trimmed_width("a\u{A0}", compat), 2);
+        assert_eq!(trimmed_width("a\u{A0} \t", compat), 2);
+        assert_eq!(trimmed_width("1\u{2007}", compat), 2);
+        assert_eq!(trimmed_width("\u{202F}", compat), 1);
+    }
+    // the no-break spaces are the only whitespace characters that are not trimmed
+    for c in '\0'..=char::MAX {
+        let no_break = matches!(c, '\u{A0}' | '\u{2007}' | '\u{202F}');
+        let expected = c.is_whitespace() && !no_break;
+        assert_eq!(crate::is_trimmable_whitespace(c), expected, "{c:?}");
+    }
+}
+
+#[test]
+fn width_override_ranges() {
+    let mut overrides = WidthOverrides::new();
+    overrides.set_range('\u{E000}'..='\u{E0FF}', 2);
+    assert_eq!(overrides.get('\u{E0A0}'), Some(2));
+    assert_eq!(overrides.get('\u{E100}'), None);
+    assert_eq!(overrides.str_width("a\u{E0A0}b", Unicode9), 4);
+    assert_eq!(overrides.str_width("a\u{E100}b", Unicode9), 3);
+    // later overrides replace the overlapping part of earlier ones
+    overrides.set('\u{E010}', 1);
+    overrides.set_range('\u{E0F0}'..='\u{E1FF}', 0);
+    assert_eq!(overrides.get('\u{E00F}'), Some(2));
+    assert_eq!(overrides.get('\u{E010}'), Some(1));
+    assert_eq!(overrides.get('\u{E011}'), Some(2));
+    assert_eq!(overrides.get('\u{E0EF}'), Some(2));
+    assert_eq!(overrides.get('\u{E0F0}'), Some(0));
+    assert_eq!(overrides.get('\u{E1FF}'), Some(0));
+    assert_eq!(overrides.get('\u{E200}'), None);
+
+    let mut overrides = WidthOverrides::new();
+    overrides
+        .set_from_str("# nerd font icons\nE000..E0FF;2\n\n4E00 ; 1 # CJK\n")
+        .unwrap();
+    assert_eq!(overrides.get('\u{E0A0}'), Some(2));
+    assert_eq!(overrides.str_width("\u{E0A0}\u{4E00}", Unicode14), 3);
+    let err = overrides.set_from_str("E000;2\nE001").unwrap_err();
+    assert_eq!(err.line(), 2);
+    assert!(overrides.set_from_str("D800;1").is_err());
+    assert!(overrides.set_from_str("110000;1").is_err());
+    assert!(overrides.set_from_str("E000;x").is_err());
+    // invalid input does not apply any overrides
+    assert_eq!(overrides.get('\u{E000}'), Some(2));
+    assert_eq!(overrides.get('\u{E001}'), Some(2));
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn visualize_invisibles() {
+    for compat in [Unicode9, Unicode14] {
+        let s = "a\u{200B}b\u{2060}c\u{301}";
+        let options = WidthOptions::new(compat);
+        assert_eq!(str_width_with(s, options), 3);
+        assert_eq!(str_width_with(s, options.visualize_invisibles(true)), 5);
+        assert_eq!(str_width_with(s, options.visualize_invisibles(false)), 3);
+        let options = options.visualize_invisibles(true);
+        assert_eq!(str_width_with("\u{200C}\u{200D}", options), 2);
+        assert_eq!(grapheme_width_with("\u{200B}", options), 1);
+    }
+}
+
+#[test]
+fn wrap_char_iterator() {
+    let inputs = [
+        "",
+        "hello world",
+        "你好世界",
+        "a\nb\r\n\nc\n",
+        "e\u{301}e\u{301}e\u{301}",
+        "👨\u{200D}👩\u{200D}👧 🇩🇪🇩🇪 ✔\u{FE0F}",
+    ];
+    for compat in [Unicode9, Unicode14] {
+        for s in inputs {
+            for max_width in 0..6 {
+                let expected: Vec<Vec<char>> = wrap(s, max_width, compat)
+                    .into_iter()
+                    .map(|line| line.chars().collect())
+                    .collect();
+                let lines: Vec<Vec<char>> = wrap_chars(s.chars(), max_width, compat).collect();
+                assert_eq!(lines, expected, "{s:?} {max_width}");
+            }
+        }
+        // a character that is wider than the line is placed on its own line
+        let lines: Vec<Vec<char>> = wrap_chars("a你b".chars(), 1, compat).collect();
+        assert_eq!(lines, [vec!['a'], vec!['你'], vec!['b']]);
+    }
+}
+
+/// 64-bit FNV-1a, used to hash the generated tables in a way that
+/// is stable across rust versions (unlike `DefaultHasher`).
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01B3)
+    })
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xCBF2_9CE4_8422_2325;
+
+// Snapshot hashes of the generated tables. These only change when the tables
+// are regenerated with `cargo xtask gen-tables`. After bumping the unicode version
+// (or otherwise deliberately changing the tables) replace the expected hash with
+// the one reported by the failing test. A failure without regenerating
+// the tables means that a generated file was edited by accident.
+const TABLES_UNICODE_VERSION: (u8, u8, u8) = (15, 0, 0);
+const WIDTH_TABLES_HASH: u64 = 0x4CFF_71E4_6AFF_EE88;
+#[cfg(feature = "emoji")]
+const EMOJI_VARIATIONS_HASH: u64 = 0xA694_544E_9E82_E8B8;
+
+#[test]
+fn width_tables_snapshot() {
+    use grapheme_width_tables::{TABLE_0, TABLE_1, TABLE_2};
+    assert_eq!(crate::UNICODE_VERSION, TABLES_UNICODE_VERSION);
+    let hash = [&TABLE_0[..], &TABLE_1[..], &TABLE_2[..]]
+        .iter()
+        .fold(FNV_OFFSET_BASIS, |hash, table| fnv1a(hash, table));
+    assert_eq!(
+        hash, WIDTH_TABLES_HASH,
+        "width tables changed: {hash:#018X}"
+    );
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn tables_crate_lookup() {
+    assert_eq!(
+        crate::UNICODE_VERSION,
+        grapheme_width_tables::UNICODE_VERSION
+    );
+    for c in (0..=char::MAX as u32).filter_map(char::from_u32) {
+        assert_eq!(
+            packed_width(c),
+            grapheme_width_tables::lookup_width(c),
+            "{c:?}"
+        );
+        if !c.is_ascii() {
+            let width = grapheme_width_tables::lookup_width(c);
+            assert_eq!(char_width_unicode9(c), usize::from(width), "{c:?}");
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "emoji")]
+fn emoji_variations_snapshot() {
+    let trie = crate::emoji_variations::EMOJI_VARIATIONS;
+    assert_eq!(crate::UNICODE_VERSION, TABLES_UNICODE_VERSION);
+    let mut hash = FNV_OFFSET_BASIS;
+    for words in [trie.tree1_level1, trie.tree2_level2, trie.tree3_level3] {
+        for word in words {
+            hash = fnv1a(hash, &word.to_le_bytes());
+        }
+    }
+    for bytes in [trie.tree2_level1, trie.tree3_level1, trie.tree3_level2] {
+        hash = fnv1a(hash, bytes);
+    }
+    assert_eq!(
+        hash, EMOJI_VARIATIONS_HASH,
+        "emoji variations changed: {hash:#018X}"
+    );
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn compare_widths() {
+    for compat in [Unicode9, Unicode14] {
+        assert!(same_width("ab", "你", compat));
+        assert!(same_width("你", "ab", compat));
+        assert!(same_width("e\u{301}\u{302}", "e", compat));
+        assert!(same_width("", "\u{200B}", compat));
+        let long = "x".repeat(100_000);
+        assert!(!same_width("abc", &long, compat));
+        assert!(!same_width(&long, "abc", compat));
+        assert!(!same_width(&long, &"你".repeat(49_999), compat));
+        assert!(same_width(&long, &"你".repeat(50_000), compat));
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn grapheme_measurer() {
+    let s = "e\u{301}a\u{300}\u{302}🇩🇪👨\u{200D}👩\u{200D}👧x";
+    let graphemes: Vec<_> = GraphemeMeasurer::new(s, Unicode9).collect();
+    assert_eq!(
+        graphemes,
+        [
+            ("e\u{301}", 1),
+            ("a\u{300}\u{302}", 1),
+            ("🇩🇪", 2),
+            ("👨\u{200D}👩\u{200D}👧", 2),
+            ("x", 1),
+        ]
+    );
+    assert_eq!(GraphemeMeasurer::new("", Unicode9).next(), None);
+    #[cfg(feature = "emoji")]
+    {
+        let graphemes: Vec<_> = GraphemeMeasurer::new("✔\u{FE0F}✔", Unicode14).collect();
+        assert_eq!(graphemes, [("✔\u{FE0F}", 2), ("✔", 1)]);
+        let graphemes: Vec<_> = GraphemeMeasurer::new("✔\u{FE0F}✔", Unicode9).collect();
+        assert_eq!(graphemes, [("✔\u{FE0F}", 1), ("✔", 1)]);
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn cluster_byte_ranges_and_widths() {
+    for compat in [Unicode9, Unicode14] {
+        assert_eq!(
+            cluster_widths("a甲\u{1F1E9}\u{1F1EA}", compat),
+            [(0..1, 1), (1..4, 2), (4..12, 2)]
+        );
+        assert_eq!(
+            cluster_widths("e\u{301}\r\n你", compat),
+            [(0..3, 1), (3..5, 0), (5..8, 2)]
+        );
+        assert_eq!(cluster_widths("", compat), []);
+    }
+}
+
+#[test]
+fn segmented_width_sums_clusters() {
+    use crate::segmented_width;
+
+    for compat in [Unicode9, Unicode14] {
+        let family = ["👨\u{200D}👩\u{200D}👧"];
+        assert_eq!(segmented_width(family, compat), 2);
+        let clusters = vec![
+            String::from("e\u{301}"),
+            String::from("甲"),
+            String::from("x"),
+        ];
+        assert_eq!(segmented_width(&clusters, compat), 4);
+        assert_eq!(segmented_width(clusters, compat), 4);
+        assert_eq!(segmented_width::<_, &str>([], compat), 0);
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn uax29_grapheme_indices() {
+    use crate::segmented_width;
+
+    let clusters = |s| grapheme_indices(s).collect::<Vec<_>>();
+    assert_eq!(clusters(""), []);
+    assert_eq!(clusters("ab"), [(0, "a"), (1, "b")]);
+    // CRLF is a single cluster, LF CR is not
+    assert_eq!(clusters("a\r\nb"), [(0, "a"), (1, "\r\n"), (3, "b")]);
+    assert_eq!(clusters("\n\r"), [(0, "\n"), (1, "\r")]);
+    // combining marks do not attach to control characters
+    assert_eq!(clusters("\n\u{301}"), [(0, "\n"), (1, "\u{301}")]);
+    assert_eq!(
+        clusters("e\u{301}\u{302}x"),
+        [(0, "e\u{301}\u{302}"), (5, "x")]
+    );
+    // emoji ZWJ sequences and emoji modifiers
+    let family = "👨\u{200D}👩\u{200D}👧";
+    assert_eq!(clusters(family), [(0, family)]);
+    assert_eq!(clusters("👍🏽👍"), [(0, "👍🏽"), (8, "👍")]);
+    // a ZWJ only joins pictographs
+    assert_eq!(clusters("a\u{200D}b"), [(0, "a\u{200D}"), (4, "b")]);
+    // flags are formed by pairs of regional indicators
+    assert_eq!(clusters("🇩🇪🇫🇷🇺"), [(0, "🇩🇪"), (8, "🇫🇷"), (16, "🇺")]);
+    // hangul syllables composed from jamo
+    assert_eq!(
+        clusters("\u{1100}\u{1161}\u{11A8}\u{AC00}\u{11A8}\u{AC01}\u{1161}"),
+        [
+            (0, "\u{1100}\u{1161}\u{11A8}"),
+            (9, "\u{AC00}\u{11A8}"),
+            (15, "\u{AC01}"),
+            (18, "\u{1161}"),
+        ]
+    );
+    // spacing marks and prepended concatenation marks
+    assert_eq!(clusters("\u{0915}\u{093E}"), [(0, "\u{0915}\u{093E}")]);
+    assert_eq!(clusters("\u{0600}1"), [(0, "\u{0600}1")]);
+    for (s, width) in [(family, 2), ("🇩🇪🇫🇷🇺", 6), ("a\r\nb", 2)] {
+        let clusters = grapheme_indices(s).map(|(_, cluster)| cluster);
+        assert_eq!(segmented_width(clusters, Unicode14), width);
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn posix_wcwidth() {
+    assert_eq!(wcwidth('a'), 1);
+    assert_eq!(wcwidth('\0'), 0);
+    assert_eq!(wcwidth('\x07'), -1);
+    assert_eq!(wcwidth('\n'), -1);
+    assert_eq!(wcwidth('\x7f'), -1);
+    assert_eq!(wcwidth('\u{85}'), -1);
+    assert_eq!(wcwidth('\u{301}'), 0);
+    assert_eq!(wcwidth('\u{200B}'), 0);
+    assert_eq!(wcwidth('甲'), 2);
+
+    assert_eq!(wcswidth(""), 0);
+    assert_eq!(wcswidth("e\u{301}甲"), 3);
+    assert_eq!(wcswidth("a\tb"), -1);
+    assert_eq!(wcswidth("甲\x1b[0m"), -1);
+    // graphemes are not taken into account
+    assert_eq!(wcswidth("👨\u{200D}👩"), 4);
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn display_value_width() {
+    /// Writes the emoji and its variation selector in separate calls
+    struct Check(bool);
+
+    impl fmt::Display for Check {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("[✔")?;
+            if self.0 {
+                f.write_char('\u{FE0F}')?;
+            }
+            f.write_str("] 完了")
+        }
+    }
+
+    for compat in [Unicode9, Unicode14] {
+        assert_eq!(display_width(&12345, compat), 5);
+        assert_eq!(display_width(&-1.5f64, compat), 4);
+        assert_eq!(display_width("甲乙", compat), 4);
+        for value in [Check(false), Check(true)] {
+            assert_eq!(
+                display_width(&value, compat),
+                str_width(&value.to_string(), compat)
+            );
+        }
+        assert_eq!(display_width(&format_args!("{:>6}|", "甲"), compat), 8);
+    }
+    #[cfg(feature = "emoji")]
+    assert_eq!(display_width(&Check(true), Unicode14), 9);
+    assert_eq!(display_width(&Check(true), Unicode9), 8);
+}
+
+#[test]
+fn unicode_compat_levels() {
+    for compat in [Unicode9, Unicode14] {
+        assert_eq!(UnicodeCompat::from_level(compat.as_level()), Some(compat));
+    }
+    assert_eq!(Unicode9.as_level(), 0);
+    assert_eq!(Unicode14.as_level(), 1);
+    assert_eq!(UnicodeCompat::from_level(2), None);
+    assert_eq!(UnicodeCompat::from_level(u32::MAX), None);
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn enclosed_alphanumeric_supplement() {
+    for compat in [Unicode9, Unicode14] {
+        // east asian width ambiguous: parenthesized and circled latin letters
+        assert_eq!(str_width("\u{1F110}", compat), 1);
+        assert_eq!(str_width("\u{1F130}", compat), 1);
+        // NEGATIVE SQUARED LATIN CAPITAL LETTER A has text presentation by default
+        assert_eq!(str_width("\u{1F170}", compat), 1);
+        // squared letters with emoji presentation (east asian wide)
+        for c in [
+            '\u{1F18E}',
+            '\u{1F191}',
+            '\u{1F19A}',
+            '\u{1F201}',
+            '\u{1F21A}',
+            '\u{1F250}',
+        ] {
+            assert_eq!(str_width(c.encode_utf8(&mut [0; 4]), compat), 2, "{c:?}");
+        }
+        // SQUARED HIRAGANA HOKA and other squared CJK are east asian wide
+        assert_eq!(str_width("\u{1F200}", compat), 2);
+        assert_eq!(str_width("\u{1F210}", compat), 2);
+    }
+    #[cfg(feature = "emoji")]
+    assert_eq!(str_width("\u{1F170}\u{FE0F}", Unicode14), 2);
+}
+
+/// Runs the byte matching of VS15/VS16 with the debug cross-check enabled
+#[test]
+#[cfg(feature = "emoji")]
+#[cfg(not(feature = "ascii-only"))]
+fn variation_selector_byte_patterns() {
+    use crate::{resolve_presentation, Presentation};
+    // U+FE0D and U+FE00 share the leading bytes with VS15/VS16
+    let s = "✔\u{FE0E}✔\u{FE0F}✔\u{FE0D}✔\u{FE00}✔\u{FE0F}\u{FE0E}";
+    assert_eq!(str_width(s, Unicode14), 1 + 2 + 1 + 1 + 2);
+    assert_eq!(char_width_unicode14('✔', "\u{FE0E}"), 1);
+    assert_eq!(char_width_unicode14('✔', "\u{FE0F}"), 2);
+    assert_eq!(char_width_unicode14('✔', "\u{FE0D}"), 1);
+    assert_eq!(
+        resolve_presentation('✔', "\u{FE0F}"),
+        Some(Presentation::Emoji)
+    );
+    assert_eq!(
+        resolve_presentation('✔', "\u{FE0E}"),
+        Some(Presentation::Text)
+    );
+    assert_eq!(
+        resolve_presentation('✔', "\u{FE00}"),
+        Some(Presentation::Text)
+    );
+    assert_eq!(column_range("✔\u{FE0F}a", 0..3, Unicode14), 0..2);
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn unicode9_text_variation() {
+    // characters that are not emoji variations are unaffected
+    assert_eq!(char_width_unicode9_vs('a', "\u{FE0E}"), 1);
+    assert_eq!(char_width_unicode9_vs('甲', "\u{FE0E}"), 2);
+    assert_eq!(char_width_unicode9_vs('\u{301}', ""), 0);
+    #[cfg(feature = "emoji")]
+    {
+        // WATCH defaults to emoji presentation
+        assert_eq!(char_width_unicode9('⌚'), 2);
+        assert_eq!(char_width_unicode9_vs('⌚', ""), 2);
+        assert_eq!(char_width_unicode9_vs('⌚', "\u{FE0E}"), 1);
+        assert_eq!(char_width_unicode9_vs('⌚', "\u{FE0F}"), 2);
+        // VS16 is ignored unlike with unicode 14
+        assert_eq!(char_width_unicode9_vs('✔', "\u{FE0F}"), 1);
+        assert_eq!(char_width_unicode14('✔', "\u{FE0F}"), 2);
+        assert_eq!(char_width_unicode9_vs('✔', "\u{FE0E}"), 1);
+        assert_eq!(char_width_unicode9_vs('✔', "\u{FE0D}"), 1);
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn truncate_ellipsis_writer() {
+    let truncate = |s, max_width, ellipsis| {
+        let mut buf = String::new();
+        truncate_ellipsis_into(&mut buf, s, max_width, ellipsis, Unicode9).unwrap();
+        buf
+    };
+    // single column ellipsis
+    assert_eq!(truncate("hello world", 6, "…"), "hello…");
+    assert_eq!(truncate("hello", 5, "…"), "hello");
+    assert_eq!(truncate("你好世界", 5, "…"), "你好…");
+    assert_eq!(truncate("你好世界", 4, "…"), "你…");
+    // multi column ellipsis
+    assert_eq!(truncate("hello world", 8, "..."), "hello...");
+    assert_eq!(truncate("hello", 5, "..."), "hello");
+    assert_eq!(truncate("hello!", 5, "..."), "he...");
+    assert_eq!(truncate("hello", 3, "..."), "...");
+    // the ellipsis does not fit
+    assert_eq!(truncate("hello", 2, "..."), "");
+    assert_eq!(truncate("", 0, "..."), "");
+    for max_width in 0..12 {
+        for ellipsis in ["…", "..."] {
+            let truncated = truncate("你好 world", max_width, ellipsis);
+            assert!(str_width(&truncated, Unicode9) <= max_width);
+        }
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn split_fit_prefix_and_rest() {
+    for compat in [Unicode9, Unicode14] {
+        // exact fit
+        assert_eq!(split_fit("abcdef", 3, compat), ("abc", "def"));
+        assert_eq!(split_fit("你好", 4, compat), ("你好", ""));
+        // the wide character does not fit and starts the remainder
+        assert_eq!(split_fit("ab你cd", 3, compat), ("ab", "你cd"));
+        assert_eq!(split_fit("你好", 1, compat), ("", "你好"));
+        // the budget is larger than the whole string
+        assert_eq!(split_fit("abc", 10, compat), ("abc", ""));
+        assert_eq!(split_fit("", 10, compat), ("", ""));
+        // combining marks stay with their base
+        assert_eq!(split_fit("ae\u{301}b", 2, compat), ("ae\u{301}", "b"));
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn truncate_marked() {
+    for compat in [Unicode9, Unicode14] {
+        assert_eq!(truncate_to_width_marked("abc", 3, compat), ("abc", false));
+        assert_eq!(truncate_to_width_marked("abc", 5, compat), ("abc", false));
+        assert_eq!(truncate_to_width_marked("", 0, compat), ("", false));
+        assert_eq!(truncate_to_width_marked("abcd", 3, compat), ("abc", true));
+        // the wide character does not fit and is dropped
+        assert_eq!(truncate_to_width_marked("ab你", 3, compat), ("ab", true));
+        // zero width characters at the end are kept with their base
+        assert_eq!(
+            truncate_to_width_marked("abe\u{301}", 3, compat),
+            ("abe\u{301}", false)
+        );
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn last_grapheme() {
+    for compat in [Unicode9, Unicode14] {
+        assert_eq!(last_grapheme_width("ab甲", compat), Some((2, 3)));
+        assert_eq!(last_grapheme_width("ae\u{301}", compat), Some((1, 3)));
+        assert_eq!(last_grapheme_width("", compat), None);
+        assert_eq!(last_grapheme_width("a", compat), Some((1, 1)));
+        assert_eq!(last_grapheme_width("a\r\n", compat), Some((0, 2)));
+        assert_eq!(
+            last_grapheme_width("x👨\u{200D}👩\u{200D}👧", compat),
+            Some((2, 18))
+        );
+        // regional indicators are paired from the start of the run
+        assert_eq!(last_grapheme_width("🇩🇪🇩🇪", compat), Some((2, 8)));
+        assert_eq!(last_grapheme_width("🇩🇪🇩", compat), Some((2, 4)));
+    }
+    #[cfg(feature = "emoji")]
+    assert_eq!(last_grapheme_width("a✔\u{FE0F}", Unicode14), Some((2, 6)));
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn ascii_prefix_fast_path() {
+    let naive = |s: &str, compat| -> usize {
+        char_width_indices(s, compat)
+            .map(|(_, _, width)| width)
+            .sum()
+    };
+    let inputs = [
+        "",
+        "hello world",
+        "tab\tand\x07bell\n",
+        "ascii then 你好 and more ascii",
+        "é at the start",
+        "1\u{FE0F}\u{20E3}",
+        "keycap 1\u{FE0F}\u{20E3} in the middle",
+        "#\u{20E3}",
+        "ends with a keycap base 9",
+        "*\u{FE0E}",
+    ];
+    for compat in [Unicode9, Unicode14] {
+        for s in inputs {
+            assert_eq!(str_width(s, compat), naive(s, compat), "{s:?}");
+        }
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn checked_width() {
+    for compat in [Unicode9, Unicode14] {
+        assert_eq!(checked_str_width("ab你", compat), Some(4));
+        assert_eq!(checked_str_width("", compat), Some(0));
+        assert_eq!(
+            checked_str_width_after(usize::MAX - 2, "ab", compat),
+            Some(usize::MAX)
+        );
+        assert_eq!(checked_str_width_after(usize::MAX - 2, "abc", compat), None);
+        assert_eq!(checked_str_width_after(usize::MAX - 1, "你", compat), None);
+        // zero width characters never overflow
+        assert_eq!(
+            checked_str_width_after(usize::MAX, "\u{301}\u{200B}", compat),
+            Some(usize::MAX)
+        );
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn spacing_marks() {
+    for compat in [Unicode9, Unicode14] {
+        // DEVANAGARI SIGN VISARGA and VOWEL SIGN AA are spacing marks
+        assert_eq!(str_width("\u{0903}", compat), 1);
+        assert_eq!(str_width("\u{0915}\u{093E}", compat), 2);
+        assert_eq!(
+            next_grapheme_width("\u{0915}\u{093E}\u{0915}", compat),
+            Some((2, 6))
+        );
+        assert_eq!(
+            last_grapheme_width("\u{0915}\u{0915}\u{093E}", compat),
+            Some((2, 6))
+        );
+        // the vowel sign is not split from its consonant
+        assert_eq!(truncate_to_width("\u{0915}\u{093E}", 1, compat), "");
+        // THAI CHARACTER SARA AM is a spacing mark but not a combining mark
+        assert_eq!(str_width("\u{0E01}\u{0E33}", compat), 2);
+        // spacing marks keep their east asian width
+        assert_eq!(str_width("\u{16FF0}", compat), 2);
+        // AHOM CONSONANT SIGN MEDIAL RA is a nonspacing mark (Mn) in unicode 15.0
+        assert_eq!(str_width("\u{1171E}", compat), 0);
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn musical_symbol_combining_marks() {
+    for compat in [Unicode9, Unicode14] {
+        // MUSICAL SYMBOL QUARTER NOTE + COMBINING FLAG-1
+        assert_eq!(str_width("\u{1D15F}\u{1D16E}", compat), 1);
+        // MUSICAL SYMBOL NOTEHEAD BLACK + COMBINING STEM + COMBINING FLAG-2
+        assert_eq!(str_width("\u{1D158}\u{1D165}\u{1D16F}", compat), 1);
+        // the stems and flags (Mc with Grapheme_Cluster_Break=Extend), nonspacing (Mn)
+        // marks and format characters of the block are zero width
+        for c in ['\u{1D165}']
+            .into_iter()
+            .chain('\u{1D167}'..='\u{1D169}')
+            .chain('\u{1D16E}'..='\u{1D182}')
+            .chain('\u{1D185}'..='\u{1D18B}')
+            .chain('\u{1D1AA}'..='\u{1D1AD}')
+        {
+            assert_eq!(str_width(c.encode_utf8(&mut [0; 4]), compat), 0, "{c:?}");
+        }
+        assert_eq!(grapheme_width("\u{1D15F}\u{1D16E}\u{1D16F}", compat), 1);
+        // COMBINING TREMOLO-1 and COMBINING AUGMENTATION DOT are spacing marks
+        assert_eq!(str_width("\u{1D166}", compat), 1);
+        assert_eq!(str_width("\u{1D16D}", compat), 1);
+    }
+}
+
+#[test]
+#[cfg(not(feature = "ascii-only"))]
+fn width_option_presets() {
+    let cjk = WidthOptions::cjk_modern();
+    assert_eq!(cjk.unicode_compat(), Unicode14);
+    assert!(cjk.ambiguous_wide);
+    assert_eq!(cjk, WidthOptions::new(Unicode14).ambiguous_wide(true));
+    let western = WidthOptions::western_legacy();
+    assert_eq!(western.unicode_compat(), Unicode9);
+    assert!(!western.ambiguous_wide);
+    assert_eq!(western, WidthOptions::default());
+
+    // WHITE CIRCLE is ambiguous
+    assert_eq!(str_width_with("a○", cjk), 3);
+    assert_eq!(str_width_with("a○", western), 2);
+    assert_eq!(str_width_with("你\u{301}", cjk), 2);
+    #[cfg(feature = "emoji")]
+    {
+        assert_eq!(str_width_with("✔\u{FE0F}", cjk), 2);
+        assert_eq!(str_width_with("✔\u{FE0F}", western), 1);
+    }
+}
@@ -0,0 +1,20 @@
+use crate::{char_width_unicode9, packed_width, str_width};
+use crate::{UnicodeCompat::Unicode14, UnicodeCompat::Unicode9};
+
+#[test]
+fn ascii_widths_are_exact() {
+    for c in (0..0x80u8).map(char::from) {
+        let expected = usize::from(!c.is_ascii_control());
+        assert_eq!(char_width_unicode9(c), expected, "{c:?}");
+    }
+    assert_eq!(str_width("hello\tworld", Unicode9), 10);
+    assert_eq!(str_width("hello\tworld", Unicode14), 10);
+}
+
+#[test]
+fn non_ascii_uses_fallback() {
+    assert_eq!(char_width_unicode9('甲'), 1);
+    assert_eq!(str_width("\u{3000}", Unicode9), 1);
+    assert_eq!(str_width("\u{3000}", Unicode14), 1);
+    assert_eq!(packed_width('甲'), 1);
+}
@@ -0,0 +1,121 @@
+use crate::{char_width_unicode9, is_emoji_variation, UnicodeCompat};
+
+/// Text variation selector (VS15)
+pub(crate) const VS15: char = '\u{FE0E}';
+/// Emoji variation selector (VS16)
+pub(crate) const VS16: char = '\u{FE0F}';
+
+/// The width a character adds to text that is processed one character at a time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Advance {
+    /// The character occupies this many additional columns.
+    Width(usize),
+    /// The character is a variation selector that changes the width of the
+    /// preceding character from `base_width` to `width`.
+    Variation { base_width: usize, width: usize },
+}
+
+/// Resolves variation selectors for text that is processed one character at a
+/// time, shared by [`ColumnTracker`], [`GridWriter`](crate::GridWriter) and
+/// [`display_width`](crate::display_width).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub(crate) struct VariationState {
+    /// Width of the last character if its presentation can still be
+    /// changed by a following variation selector.
+    base_width: Option<usize>,
+}
+
+impl VariationState {
+    pub(crate) const fn new() -> VariationState {
+        VariationState { base_width: None }
+    }
+
+    /// Forgets the last character, for example after a control character.
+    pub(crate) fn reset(&mut self) {
+        self.base_width = None
+    }
+
+    /// Returns how `c` changes the width of the text processed so far.
+    pub(crate) fn advance(&mut self, c: char, unicode_compat: UnicodeCompat) -> Advance {
+        let base_width = self.base_width.take();
+        match (c, base_width) {
+            (VS15 | VS16, Some(base_width)) => Advance::Variation {
+                base_width,
+                width: if c == VS15 { 1 } else { 2 },
+            },
+            _ => {
+                let width = char_width_unicode9(c);
+                if unicode_compat == UnicodeCompat::Unicode14 && is_emoji_variation(c) {
+                    self.base_width = Some(width);
+                }
+                Advance::Width(width)
+            }
+        }
+    }
+}
+
+/// Tracks the cursor position of text written to a terminal.
+///
+/// Text can be fed to the tracker incrementally (for example while reading
+/// the output of a child process) as variation selectors are resolved
+/// across calls to [`push`](ColumnTracker::push).
+///
+/// Carriage returns (`\r`) move the cursor back to the start of the line,
+/// so subsequent text overwrites the existing content just like in a terminal.
+/// A newline (`\n`) moves the cursor to the start of the next line. A `\r\n`
+/// pair therefore only advances the line once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ColumnTracker {
+    compat: UnicodeCompat,
+    column: usize,
+    line: usize,
+    variation: VariationState,
+}
+
+impl ColumnTracker {
+    /// Creates a tracker with the cursor at the start of the first line.
+    pub fn new(unicode_compat: UnicodeCompat) -> ColumnTracker {
+        ColumnTracker {
+            compat: unicode_compat,
+            column: 0,
+            line: 0,
+            variation: VariationState::new(),
+        }
+    }
+
+    /// The column the cursor is currently placed at.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// The number of lines that have been started (by a newline) so far.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Advances the cursor by the text `s`.
+    pub fn push_str(&mut self, s: &str) {
+        s.chars().for_each(|c| self.push(c))
+    }
+
+    /// Advances the cursor by a single character `c`.
+    pub fn push(&mut self, c: char) {
+        match c {
+            '\r' => {
+                self.variation.reset();
+                self.column = 0;
+            }
+            '\n' => {
+                self.variation.reset();
+                self.column = 0;
+                self.line += 1;
+            }
+            _ => match self.variation.advance(c, self.compat) {
+                Advance::Width(width) => self.column += width,
+                Advance::Variation { base_width, width } => {
+                    self.column = self.column - base_width + width
+                }
+            },
+        }
+    }
+}
@@ -0,0 +1,201 @@
+#[cfg(not(feature = "ascii-only"))]
+use crate::grapheme_break::GRAPHEME_BREAK;
+
+/// The grapheme cluster break class of a character as defined in
+/// [UAX #29](https://www.unicode.org/reports/tr29/#Grapheme_Cluster_Break_Property_Values).
+/// `Extended_Pictographic` is treated as an additional class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ascii-only", allow(dead_code))]
+pub(crate) enum GraphemeBreak {
+    Other,
+    Cr,
+    Lf,
+    Control,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    Lv,
+    Lvt,
+    ExtendedPictographic,
+}
+
+/// The first precomposed hangul syllable (U+AC00)
+const HANGUL_SYLLABLES_START: u32 = 0xAC00;
+/// The last precomposed hangul syllable (U+D7A3)
+const HANGUL_SYLLABLES_END: u32 = 0xD7A3;
+
+/// Returns the grapheme cluster break class of `c`.
+///
+/// With the `ascii-only` feature only the ASCII control characters are
+/// classified, all other characters are `Other`.
+fn grapheme_break(c: char) -> GraphemeBreak {
+    match c {
+        '\r' => return GraphemeBreak::Cr,
+        '\n' => return GraphemeBreak::Lf,
+        '\0'..='\x1f' | '\x7f' => return GraphemeBreak::Control,
+        '\x20'..='\x7e' => return GraphemeBreak::Other,
+        _ => (),
+    }
+    let c = c as u32;
+    if (HANGUL_SYLLABLES_START..=HANGUL_SYLLABLES_END).contains(&c) {
+        // every 28th syllable has no trailing consonant
+        return if (c - HANGUL_SYLLABLES_START) % 28 == 0 {
+            GraphemeBreak::Lv
+        } else {
+            GraphemeBreak::Lvt
+        };
+    }
+    #[cfg(not(feature = "ascii-only"))]
+    {
+        use core::cmp::Ordering;
+
+        let range = GRAPHEME_BREAK.binary_search_by(|&(start, end, _)| {
+            if end < c {
+                Ordering::Less
+            } else if start > c {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        });
+        match range {
+            Ok(i) => GRAPHEME_BREAK[i].2,
+            Err(_) => GraphemeBreak::Other,
+        }
+    }
+    #[cfg(feature = "ascii-only")]
+    {
+        GraphemeBreak::Other
+    }
+}
+
+/// Returns whether the pair rules of UAX #29 keep `next` in the same cluster as `prev`.
+///
+/// The rules for emoji ZWJ sequences (GB11) and flags (GB12, GB13) also depend
+/// on the characters before `prev`, for these pairs `None` is returned.
+fn joins(prev: GraphemeBreak, next: GraphemeBreak) -> Option<bool> {
+    use GraphemeBreak::*;
+
+    let joins = match (prev, next) {
+        // GB3
+        (Cr, Lf) => true,
+        // GB4, GB5
+        (Control | Cr | Lf, _) | (_, Control | Cr | Lf) => false,
+        // GB6 - GB8: hangul syllable sequences
+        (L, L | V | Lv | Lvt) | (Lv | V, V | T) | (Lvt | T, T) => true,
+        // GB9 - GB9b
+        (_, Extend | Zwj | SpacingMark) | (Prepend, _) => true,
+        // GB11 - GB13
+        (Zwj, ExtendedPictographic) | (RegionalIndicator, RegionalIndicator) => return None,
+        // GB999
+        _ => false,
+    };
+    Some(joins)
+}
+
+/// Returns whether there is always a grapheme cluster boundary between `prev` and `next`,
+/// regardless of the characters before `prev`.
+pub(crate) fn is_guaranteed_boundary(prev: char, next: char) -> bool {
+    joins(grapheme_break(prev), grapheme_break(next)) == Some(false)
+}
+
+/// State of the UAX #29 segmentation while a grapheme cluster is extended
+/// one character at a time.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct GraphemeExtender {
+    prev: GraphemeBreak,
+    /// An odd number of regional indicators precedes the next character (GB12, GB13)
+    odd_regional_indicators: bool,
+    /// The cluster ends with Extended_Pictographic Extend* (GB11)
+    pictographic: bool,
+    /// The cluster ends with Extended_Pictographic Extend* ZWJ (GB11)
+    pictographic_zwj: bool,
+}
+
+impl GraphemeExtender {
+    /// Starts a new grapheme cluster with the character `first`.
+    pub(crate) fn new(first: char) -> GraphemeExtender {
+        let prev = grapheme_break(first);
+        GraphemeExtender {
+            prev,
+            odd_regional_indicators: prev == GraphemeBreak::RegionalIndicator,
+            pictographic: prev == GraphemeBreak::ExtendedPictographic,
+            pictographic_zwj: false,
+        }
+    }
+
+    /// Returns whether `c` belongs to the current grapheme cluster.
+    /// If it does, it becomes the last character of the cluster.
+    pub(crate) fn extends(&mut self, c: char) -> bool {
+        use GraphemeBreak::*;
+
+        let next = grapheme_break(c);
+        let extends = match joins(self.prev, next) {
+            Some(joins) => joins,
+            None if next == RegionalIndicator => self.odd_regional_indicators,
+            None => self.pictographic_zwj,
+        };
+        if extends {
+            self.pictographic_zwj = self.pictographic && next == Zwj;
+            self.pictographic =
+                next == ExtendedPictographic || (self.pictographic && next == Extend);
+            self.odd_regional_indicators =
+                next == RegionalIndicator && !self.odd_regional_indicators;
+            self.prev = next;
+        }
+        extends
+    }
+}
+
+/// Returns the length (in bytes) of the first extended grapheme cluster in `s`
+/// according to the rules of UAX #29.
+pub(crate) fn next_grapheme_len(s: &str) -> usize {
+    let mut chars = s.char_indices();
+    let mut extender = match chars.next() {
+        Some((_, first)) => GraphemeExtender::new(first),
+        None => return 0,
+    };
+    for (i, c) in chars {
+        if !extender.extends(c) {
+            return i;
+        }
+    }
+    s.len()
+}
+
+/// Returns an iterator over the extended grapheme clusters of `s` and their byte offsets.
+///
+/// This implements the segmentation algorithm of [UAX #29](https://www.unicode.org/reports/tr29/)
+/// with the tables of this crate, so the (optional) `unicode-segmentation`
+/// dependency is not required. The rules of unicode 15.0 are implemented,
+/// so indic conjuncts (rule GB9c added in unicode 15.1) are not joined.
+/// The same segmentation is used by [`next_grapheme_width`](crate::next_grapheme_width)
+/// and the other grapheme aware functions of this crate.
+pub fn grapheme_indices(s: &str) -> impl Iterator<Item = (usize, &str)> + '_ {
+    GraphemeIndices { s, offset: 0 }
+}
+
+struct GraphemeIndices<'a> {
+    s: &'a str,
+    offset: usize,
+}
+
+impl<'a> Iterator for GraphemeIndices<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<(usize, &'a str)> {
+        let rem = &self.s[self.offset..];
+        let len = next_grapheme_len(rem);
+        if len == 0 {
+            return None;
+        }
+        let start = self.offset;
+        self.offset += len;
+        Some((start, &rem[..len]))
+    }
+}
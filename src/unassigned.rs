@@ -0,0 +1,488 @@
+//! Generated by `cargo xtask gen-tables`, do not edit by hand.
+//! This file contains a UCD tri-set for determining whether a codepoint is unassigned (general category Cn).
+//! It was generated from UCD 15.0.0
+//! Source: local archive UCD.zip (SHA-256 15cfadb6bb6748fb78b9fd741102c3a191554b8fae12b74a795ceb189d963fa1)
+
+#[cfg(not(feature = "ascii-only"))]
+pub(crate) const UNASSIGNED: &'static ::ucd_trie::TrieSet = &::ucd_trie::TrieSet {
+    tree1_level1: &[
+        0u64,
+        0u64,
+        0u64,
+        0u64,
+        0u64,
+        0u64,
+        0u64,
+        0u64,
+        0u64,
+        0u64,
+        0u64,
+        0u64,
+        0u64,
+        216172782113783808u64,
+        17179879439u64,
+        0u64,
+        0u64,
+        0u64,
+        0u64,
+        0u64,
+        281474976710656u64,
+        25165824u64,
+        71680u64,
+        18437868815850209024u64,
+        0u64,
+        0u64,
+        0u64,
+        0u64,
+        16384u64,
+        6144u64,
+        18445618173802708992u64,
+        1729382256910270464u64,
+    ],
+    tree2_level1: &[
+        0u8, 1u8, 2u8, 3u8, 3u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8, 10u8, 11u8, 12u8, 13u8, 14u8,
+        15u8, 16u8, 17u8, 18u8, 19u8, 20u8, 21u8, 22u8, 23u8, 24u8, 25u8, 3u8, 26u8, 27u8, 28u8,
+        3u8, 3u8, 3u8, 29u8, 3u8, 3u8, 3u8, 3u8, 3u8, 30u8, 31u8, 32u8, 33u8, 34u8, 35u8, 36u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 37u8, 38u8, 39u8, 40u8, 3u8, 41u8, 35u8,
+        38u8, 42u8, 43u8, 44u8, 45u8, 46u8, 47u8, 48u8, 49u8, 50u8, 51u8, 3u8, 52u8, 3u8, 53u8,
+        54u8, 55u8, 56u8, 57u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 58u8, 59u8, 60u8, 61u8,
+        3u8, 62u8, 63u8, 64u8, 3u8, 3u8, 65u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 66u8,
+        67u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 68u8, 69u8, 3u8, 3u8, 3u8, 3u8, 70u8,
+        71u8, 72u8, 73u8, 74u8, 3u8, 75u8, 76u8, 77u8, 3u8, 3u8, 3u8, 78u8, 3u8, 79u8, 80u8, 3u8,
+        81u8, 3u8, 82u8, 83u8, 84u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 85u8, 86u8, 3u8, 3u8, 3u8,
+        3u8, 87u8, 3u8, 3u8, 88u8, 3u8, 3u8, 3u8, 89u8, 90u8, 88u8, 3u8, 91u8, 3u8, 92u8, 3u8,
+        93u8, 94u8, 95u8, 3u8, 96u8, 97u8, 46u8, 3u8, 98u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 83u8, 99u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+        3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 100u8,
+        3u8, 101u8, 102u8, 103u8, 3u8, 104u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 105u8, 106u8, 35u8,
+        107u8, 3u8, 108u8, 79u8, 3u8, 109u8, 110u8,
+    ],
+    tree2_level2: &[
+        9223583143087308800u64,
+        272681836478464u64,
+        16547840u64,
+        0u64,
+        881018876128026640u64,
+        9223372244346963552u64,
+        3211631683292264465u64,
+        18410715549991290488u64,
+        869759877059461137u64,
+        142989498622329920u64,
+        869759877059600401u64,
+        18374686687157536352u64,
+        4323518207764871187u64,
+        17870283596275565112u64,
+        864693327478530048u64,
+        35747531835032096u64,
+        869196927105900544u64,
+        18442522157895369248u64,
+        139264u64,
+        206159413792u64,
+        14989105459854573585u64,
+        18438581569978399616u64,
+        8646911284551352321u64,
+        18446744073441116160u64,
+        13835058398879549481u64,
+        18446744069615943840u64,
+        527765581332736u64,
+        2305843009230471168u64,
+        18446744073575342080u64,
+        57152u64,
+        3263218176u64,
+        9277978182336692736u64,
+        8388802u64,
+        12713984u64,
+        16140901064898510848u64,
+        4227858432u64,
+        13889101250810609664u64,
+        3758096384u64,
+        18302628885633695744u64,
+        18410715278833876992u64,
+        18442838612701609984u64,
+        18158790777709264896u64,
+        272678883688448u64,
+        18428729675200069632u64,
+        17294086454040854528u64,
+        18437947980687343630u64,
+        263882790666240u64,
+        939588608u64,
+        805306368u64,
+        6917529029788565504u64,
+        211110460455936u64,
+        18446744073709518848u64,
+        9223372036854833152u64,
+        1148417904979476480u64,
+        504403158265495552u64,
+        7168u64,
+        1729382256910335488u64,
+        17870283321406193408u64,
+        3233808384u64,
+        13835058056708276416u64,
+        9007199254740992u64,
+        9233223661311229984u64,
+        3377837159481344u64,
+        3758129152u64,
+        18446181123756195838u64,
+        61440u64,
+        18446743523953737728u64,
+        4294965248u64,
+        13510798882111488u64,
+        4194304u64,
+        139611588448485376u64,
+        245465970900992u64,
+        9222948724878082048u64,
+        9259542125404487680u64,
+        2155905152u64,
+        18446744072635809792u64,
+        67108864u64,
+        18442240474082181120u64,
+        17294104044075220992u64,
+        1u64,
+        25165824u64,
+        281474976710687u64,
+        32768u64,
+        281406257233920u64,
+        2147483648u64,
+        57344u64,
+        65408u64,
+        18446726481523507200u64,
+        18374686479671623680u64,
+        1125899841107968u64,
+        18158759988162461696u64,
+        4227874752u64,
+        16140901066642292736u64,
+        9223372037861425152u64,
+        18410715276690587648u64,
+        201375744u64,
+        18410715276824805368u64,
+        141291530846593u64,
+        18158724803790372864u64,
+        17293822569102706560u64,
+        211106232532992u64,
+        18446744073642442752u64,
+        11565243843608051584u64,
+        36u64,
+        524280u64,
+        196608u64,
+        281474976677632u64,
+        9271631801745408u64,
+        6917529027641081856u64,
+        9223372036854775808u64,
+        13979033059434103555u64,
+    ],
+    tree3_level1: &[
+        0u8, 1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 5u8, 9u8, 10u8, 11u8, 12u8, 13u8, 14u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 15u8, 16u8, 17u8, 7u8, 18u8, 19u8, 7u8, 20u8,
+        21u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+        5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+        5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+        5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+        5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+        5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+        5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+        5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+        5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+        5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 22u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+        5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 23u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 23u8,
+    ],
+    tree3_level2: &[
+        0u8, 1u8, 2u8, 3u8, 4u8, 2u8, 5u8, 6u8, 7u8, 7u8, 8u8, 9u8, 10u8, 11u8, 12u8, 13u8, 2u8,
+        2u8, 14u8, 15u8, 16u8, 17u8, 18u8, 7u8, 2u8, 2u8, 2u8, 2u8, 19u8, 20u8, 21u8, 7u8, 22u8,
+        23u8, 24u8, 25u8, 26u8, 7u8, 27u8, 28u8, 29u8, 30u8, 31u8, 32u8, 33u8, 34u8, 35u8, 7u8,
+        2u8, 36u8, 37u8, 38u8, 39u8, 7u8, 7u8, 7u8, 7u8, 40u8, 41u8, 42u8, 16u8, 43u8, 44u8, 45u8,
+        2u8, 46u8, 2u8, 47u8, 48u8, 49u8, 2u8, 50u8, 51u8, 52u8, 53u8, 54u8, 55u8, 56u8, 7u8, 7u8,
+        2u8, 57u8, 2u8, 58u8, 7u8, 7u8, 59u8, 60u8, 2u8, 61u8, 62u8, 63u8, 64u8, 65u8, 7u8, 7u8,
+        66u8, 7u8, 67u8, 68u8, 69u8, 70u8, 71u8, 72u8, 2u8, 73u8, 74u8, 75u8, 63u8, 7u8, 7u8, 7u8,
+        76u8, 77u8, 78u8, 7u8, 79u8, 80u8, 81u8, 7u8, 7u8, 7u8, 7u8, 82u8, 83u8, 84u8, 85u8, 86u8,
+        2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 84u8, 7u8, 2u8, 87u8,
+        2u8, 2u8, 2u8, 88u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 89u8, 37u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+        2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 90u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 65u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 75u8, 91u8, 92u8, 93u8, 2u8, 94u8, 95u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 2u8, 96u8, 7u8, 2u8, 97u8, 98u8, 99u8, 2u8, 2u8,
+        2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+        2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+        2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+        2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+        2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+        2u8, 2u8, 2u8, 100u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+        2u8, 2u8, 2u8, 2u8, 2u8, 90u8, 36u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 101u8, 2u8, 2u8, 2u8, 2u8, 102u8, 103u8, 2u8,
+        2u8, 2u8, 2u8, 2u8, 66u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 2u8, 104u8, 105u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 106u8, 107u8, 2u8, 88u8, 2u8, 2u8, 2u8,
+        108u8, 109u8, 2u8, 2u8, 110u8, 2u8, 111u8, 7u8, 112u8, 2u8, 113u8, 7u8, 7u8, 2u8, 114u8,
+        115u8, 116u8, 117u8, 118u8, 2u8, 2u8, 2u8, 2u8, 119u8, 2u8, 2u8, 2u8, 2u8, 120u8, 2u8, 2u8,
+        2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 121u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 122u8, 7u8, 7u8, 7u8, 123u8, 124u8, 125u8, 7u8,
+        126u8, 127u8, 7u8, 7u8, 7u8, 7u8, 128u8, 129u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 130u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 131u8, 2u8, 2u8, 2u8, 132u8, 2u8,
+        133u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 134u8, 135u8, 7u8, 136u8,
+        7u8, 7u8, 7u8, 137u8, 138u8, 139u8, 140u8, 7u8, 7u8, 7u8, 7u8, 141u8, 2u8, 142u8, 143u8,
+        2u8, 2u8, 124u8, 144u8, 145u8, 146u8, 7u8, 7u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+        2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 147u8, 2u8, 148u8, 2u8, 149u8, 150u8, 151u8, 152u8, 7u8,
+        2u8, 2u8, 2u8, 2u8, 2u8, 153u8, 154u8, 155u8, 2u8, 2u8, 156u8, 157u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+        2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+        2u8, 2u8, 2u8, 31u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+        2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+        2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+        2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 62u8, 2u8, 2u8, 2u8,
+        158u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+        2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+        2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+        2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+        2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+        159u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+        2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+        2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 160u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+        2u8, 2u8, 60u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+        2u8, 2u8, 2u8, 161u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+        2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+        2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+        2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 162u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 163u8, 2u8, 7u8, 7u8, 2u8, 2u8, 2u8,
+        162u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+        7u8, 7u8, 7u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+        2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+        2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+        2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 164u8,
+    ],
+    tree3_level3: &[
+        5188147320486629376u64,
+        18446744072635858944u64,
+        0u64,
+        17870283321406128128u64,
+        31525197391593592u64,
+        18446744068877746176u64,
+        13835058055282229247u64,
+        18446744073709551615u64,
+        3758096384u64,
+        17293822573397540864u64,
+        35115652612096u64,
+        17870283321406191616u64,
+        1073741824u64,
+        18446744073705357552u64,
+        277080151425024u64,
+        17293822569118433280u64,
+        280375465082880u64,
+        576601421072302080u64,
+        16430257357739001856u64,
+        18410715276690587648u64,
+        18446742978488696832u64,
+        17870846271359549504u64,
+        7944349742681555648u64,
+        4194304u64,
+        18446463146341171200u64,
+        560698157902594047u64,
+        8935141662582112256u64,
+        1080863910568919040u64,
+        196608u64,
+        8700954480097628048u64,
+        4261477888u64,
+        18446744069414584320u64,
+        18410723523027795968u64,
+        126100789566373888u64,
+        69805794236825600u64,
+        18446464797252517888u64,
+        18446744073709551104u64,
+        18444492273895866368u64,
+        285978576338026496u64,
+        18158794073022922752u64,
+        9223372041149743103u64,
+        18445833678081753088u64,
+        2305843009213693951u64,
+        281474909601792u64,
+        281474976709632u64,
+        18410715280985550848u64,
+        9205357638345539584u64,
+        18158792973511352312u64,
+        9007199254740992u64,
+        18410715276690652928u64,
+        18437736878749777920u64,
+        262144u64,
+        18446744073709551612u64,
+        277078003958400u64,
+        18158786376441528320u64,
+        293299124756176912u64,
+        18437983371746264672u64,
+        18446744056798117888u64,
+        18446744073642508032u64,
+        54043195528445952u64,
+        18446744072635809792u64,
+        18446708893565386720u64,
+        18158513697557839872u64,
+        18446744073709550592u64,
+        263883193319424u64,
+        18446744073709551488u64,
+        17293822569102704640u64,
+        4294967295u64,
+        9221120237041090560u64,
+        450359962746490240u64,
+        18446744073642508160u64,
+        3302829850623u64,
+        18446743936320929792u64,
+        65280u64,
+        281440616972288u64,
+        18302628885633695744u64,
+        36028797018964480u64,
+        246290604687296u64,
+        18410716376202412032u64,
+        5440348349863560320u64,
+        2478129086208u64,
+        18446739679924748288u64,
+        18302628889928663039u64,
+        4035225266124095488u64,
+        18446744073642442752u64,
+        18446462598732840959u64,
+        9222246136947933184u64,
+        18437877611943165952u64,
+        18446744073709551600u64,
+        65535u64,
+        18446744073705357312u64,
+        65972845150208u64,
+        9223372036854775808u64,
+        18428940781432667136u64,
+        2233785432422809536u64,
+        18446744073709486080u64,
+        18446744073575333888u64,
+        30720u64,
+        18446744069414616832u64,
+        18445899515635433471u64,
+        18374686479671623680u64,
+        10381078616065703935u64,
+        18445618139442970624u64,
+        280444182003711u64,
+        16141173743379546112u64,
+        18446744005191466496u64,
+        211106232532992u64,
+        65408u64,
+        18428729675200069632u64,
+        1649267441664u64,
+        18446735277616529408u64,
+        18446744073709551552u64,
+        18442240478376099840u64,
+        18302628889920274432u64,
+        2097152u64,
+        1441188830899077120u64,
+        16u64,
+        9511602413545461824u64,
+        132000u64,
+        824633720832u64,
+        12288u64,
+        18446462603162021888u64,
+        18446735412907999232u64,
+        272833603174528u64,
+        18446673704965373952u64,
+        18446744073709518847u64,
+        13835304345886785536u64,
+        18446744073709501440u64,
+        18446603336221261823u64,
+        8935141660703064064u64,
+        18158513697557905407u64,
+        9223530920579956735u64,
+        18446744073701163104u64,
+        18446744070421278720u64,
+        562949953421311u64,
+        18437736874454810624u64,
+        13835058055282163713u64,
+        17656363889589223440u64,
+        11603533688417621371u64,
+        17293827044190192640u64,
+        18445899648779419647u64,
+        263882790666240u64,
+        422216758984704u64,
+        18428729675200135169u64,
+        274877906943u64,
+        17293822569102770168u64,
+        18446743803126414848u64,
+        16141147355352137728u64,
+        540431955284459520u64,
+        18446445010774654976u64,
+        61440u64,
+        4227923712u64,
+        18445829280035307264u64,
+        16141112175022309376u64,
+        4611686018427452928u64,
+        18302908165613699008u64,
+        524288u64,
+        18158795172534548480u64,
+        3221225472u64,
+        281457796841472u64,
+        18446744065119617024u64,
+        63488u64,
+        18446462598732840960u64,
+        4294967293u64,
+        13835058055282163712u64,
+    ],
+};
@@ -0,0 +1,33 @@
+use crate::char_width_unicode9;
+
+/// Computes the width of `c` like the POSIX `wcwidth` function (as implemented
+/// by Markus Kuhn's widely used `wcwidth.c` and xterm).
+///
+/// Returns `-1` for control characters (C0, DEL and C1), except for the null
+/// character which is zero width. Combining marks and other zero width characters
+/// return `0`. All other characters return the width from the tables of this crate
+/// (see [`char_width_unicode9`]).
+pub fn wcwidth(c: char) -> i32 {
+    match c {
+        '\0' => 0,
+        c if c.is_control() => -1,
+        c => char_width_unicode9(c) as i32,
+    }
+}
+
+/// Computes the width of `s` like the POSIX `wcswidth` function by summing
+/// the [`wcwidth`] of all characters.
+///
+/// Returns `-1` if `s` contains a character for which [`wcwidth`] returns `-1`.
+/// Like `wcswidth`, the string is not segmented into graphemes and emoji
+/// variation selectors are not taken into account.
+pub fn wcswidth(s: &str) -> i32 {
+    let mut width = 0;
+    for c in s.chars() {
+        match wcwidth(c) {
+            -1 => return -1,
+            char_width => width += char_width,
+        }
+    }
+    width
+}
@@ -0,0 +1,65 @@
+use core::fmt;
+
+/// The number of terminal cells occupied by a character or grapheme.
+///
+/// Terminals render every grapheme into at most two cells,
+/// so a width is always zero, one or two.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Width {
+    /// Not rendered or combined with the previous character
+    #[default]
+    Zero,
+    /// Occupies a single cell
+    One,
+    /// Occupies two cells (wide characters and emoji presentation)
+    Two,
+}
+
+impl From<Width> for u8 {
+    fn from(width: Width) -> u8 {
+        match width {
+            Width::Zero => 0,
+            Width::One => 1,
+            Width::Two => 2,
+        }
+    }
+}
+
+impl From<Width> for usize {
+    fn from(width: Width) -> usize {
+        u8::from(width).into()
+    }
+}
+
+impl TryFrom<usize> for Width {
+    type Error = InvalidWidth;
+
+    fn try_from(width: usize) -> Result<Width, InvalidWidth> {
+        match width {
+            0 => Ok(Width::Zero),
+            1 => Ok(Width::One),
+            2 => Ok(Width::Two),
+            _ => Err(InvalidWidth(width)),
+        }
+    }
+}
+
+/// Error returned when converting an integer larger than two into a [`Width`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InvalidWidth(usize);
+
+impl InvalidWidth {
+    /// The width that could not be converted.
+    pub const fn value(&self) -> usize {
+        self.0
+    }
+}
+
+impl fmt::Display for InvalidWidth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid width {}, must be 0, 1 or 2", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidWidth {}
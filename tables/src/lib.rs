@@ -0,0 +1,17 @@
+//! The generated width lookup tables of the [`grapheme_width`](https://docs.rs/grapheme_width) crate.
+//!
+//! The width table is by far the largest part of `grapheme_width`. It is placed in this
+//! separate crate so that it is only recompiled when the tables are regenerated with
+//! `cargo xtask gen-tables`, not every time `grapheme_width` itself changes.
+//! [`lookup_width`] is `#[inline]` so that it is still inlined into the width
+//! functions of `grapheme_width` across the crate boundary.
+//!
+//! This crate is an implementation detail of `grapheme_width` and
+//! should not be used directly, use `grapheme_width::packed_width` instead.
+
+#![no_std]
+
+#[allow(warnings)]
+mod table;
+
+pub use table::{lookup_width, TABLE_0, TABLE_1, TABLE_2, UNICODE_VERSION};
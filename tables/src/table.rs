@@ -0,0 +1,410 @@
+//! Generated by `cargo xtask gen-tables`, do not edit by hand.
+//! This file contains a 3 level LUT for determining the display width of a unicode grapheme.
+//! It was generated from UCD 15.0.0
+//! Source: local archive UCD.zip (SHA-256 15cfadb6bb6748fb78b9fd741102c3a191554b8fae12b74a795ceb189d963fa1)
+
+#[doc = r" Version of the UCD used to generate the width lookup tables"]
+pub const UNICODE_VERSION: (u8, u8, u8) = (15u8, 0u8, 0u8);
+pub static TABLE_0: [u8; 256usize] = [
+    0u8, 1u8, 2u8, 3u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8, 10u8, 11u8, 12u8, 13u8, 14u8, 3u8, 3u8,
+    3u8, 3u8, 3u8, 3u8, 3u8, 15u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 15u8, 9u8, 9u8, 9u8, 9u8,
+    9u8, 9u8, 9u8, 16u8, 9u8, 9u8, 9u8, 9u8, 9u8, 9u8, 9u8, 16u8, 9u8, 9u8, 9u8, 9u8, 9u8, 9u8,
+    9u8, 16u8, 9u8, 9u8, 9u8, 9u8, 9u8, 9u8, 9u8, 16u8, 9u8, 9u8, 9u8, 9u8, 9u8, 9u8, 9u8, 16u8,
+    9u8, 9u8, 9u8, 9u8, 9u8, 9u8, 9u8, 16u8, 9u8, 9u8, 9u8, 9u8, 9u8, 9u8, 9u8, 16u8, 9u8, 9u8,
+    9u8, 9u8, 9u8, 9u8, 9u8, 16u8, 9u8, 9u8, 9u8, 9u8, 9u8, 9u8, 9u8, 16u8, 9u8, 9u8, 9u8, 9u8,
+    9u8, 9u8, 9u8, 16u8, 17u8, 9u8, 9u8, 9u8, 9u8, 9u8, 9u8, 16u8, 9u8, 9u8, 9u8, 9u8, 9u8, 9u8,
+    9u8, 16u8, 9u8, 9u8, 9u8, 9u8, 9u8, 9u8, 9u8, 16u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+];
+pub static TABLE_1: [u8; 2304usize] = [
+    0u8, 1u8, 0u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 3u8, 4u8, 2u8, 2u8, 2u8, 2u8, 5u8,
+    2u8, 2u8, 2u8, 6u8, 7u8, 8u8, 9u8, 2u8, 10u8, 11u8, 12u8, 13u8, 14u8, 15u8, 16u8, 17u8, 18u8,
+    19u8, 20u8, 21u8, 22u8, 23u8, 24u8, 23u8, 25u8, 26u8, 27u8, 28u8, 29u8, 30u8, 31u8, 32u8, 33u8,
+    34u8, 35u8, 36u8, 37u8, 38u8, 39u8, 40u8, 41u8, 42u8, 43u8, 44u8, 45u8, 46u8, 47u8, 48u8, 2u8,
+    49u8, 50u8, 3u8, 3u8, 2u8, 2u8, 2u8, 2u8, 2u8, 51u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 52u8, 53u8, 54u8, 55u8, 56u8, 2u8, 57u8, 2u8, 58u8, 2u8, 2u8,
+    2u8, 59u8, 60u8, 61u8, 62u8, 63u8, 64u8, 65u8, 66u8, 67u8, 2u8, 2u8, 68u8, 2u8, 2u8, 2u8, 3u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 69u8, 70u8, 2u8, 71u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 72u8, 2u8, 2u8, 73u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 74u8, 75u8, 76u8, 77u8,
+    78u8, 79u8, 80u8, 81u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 82u8,
+    83u8, 2u8, 2u8, 2u8, 2u8, 2u8, 84u8, 2u8, 1u8, 2u8, 85u8, 2u8, 2u8, 86u8, 87u8, 49u8, 49u8,
+    49u8, 88u8, 89u8, 90u8, 91u8, 49u8, 92u8, 49u8, 93u8, 94u8, 95u8, 96u8, 49u8, 49u8, 49u8, 49u8,
+    49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8,
+    49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8,
+    49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8,
+    49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8,
+    49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8,
+    49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8,
+    49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 2u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8,
+    49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8,
+    49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8,
+    49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8,
+    49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8,
+    49u8, 49u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8,
+    97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8,
+    97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8,
+    97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8,
+    97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8,
+    97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8,
+    97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8,
+    97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8,
+    97u8, 97u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8,
+    49u8, 49u8, 49u8, 49u8, 98u8, 99u8, 2u8, 2u8, 2u8, 2u8, 2u8, 100u8, 101u8, 102u8, 2u8, 2u8,
+    2u8, 2u8, 103u8, 2u8, 2u8, 104u8, 105u8, 106u8, 107u8, 108u8, 109u8, 110u8, 111u8, 112u8, 2u8,
+    2u8, 2u8, 113u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8,
+    49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8,
+    49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8,
+    49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8,
+    49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8,
+    49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8,
+    49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8,
+    49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8,
+    49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8,
+    49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8,
+    49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8,
+    49u8, 114u8, 2u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+    3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 115u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 116u8, 117u8, 118u8, 2u8, 1u8, 90u8, 119u8, 2u8, 120u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 121u8, 2u8, 2u8, 2u8, 122u8, 2u8, 123u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    124u8, 2u8, 2u8, 125u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 126u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    127u8, 128u8, 2u8, 129u8, 130u8, 2u8, 131u8, 132u8, 133u8, 134u8, 135u8, 136u8, 137u8, 138u8,
+    139u8, 36u8, 2u8, 140u8, 34u8, 141u8, 2u8, 2u8, 142u8, 143u8, 144u8, 145u8, 2u8, 2u8, 146u8,
+    147u8, 148u8, 149u8, 150u8, 2u8, 151u8, 2u8, 2u8, 2u8, 152u8, 2u8, 2u8, 2u8, 153u8, 154u8, 2u8,
+    155u8, 156u8, 157u8, 158u8, 2u8, 2u8, 2u8, 2u8, 2u8, 159u8, 2u8, 160u8, 2u8, 161u8, 162u8,
+    163u8, 2u8, 2u8, 2u8, 2u8, 164u8, 165u8, 166u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 61u8, 167u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8,
+    168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8,
+    168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8,
+    168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8,
+    168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8,
+    168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8,
+    168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8,
+    168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8,
+    168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8,
+    168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8,
+    168u8, 168u8, 168u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 169u8, 170u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 171u8, 172u8, 173u8, 49u8, 49u8, 49u8, 49u8, 49u8,
+    49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8,
+    49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8,
+    49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8,
+    49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8,
+    49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8,
+    49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 174u8, 49u8, 49u8, 49u8, 49u8,
+    49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8, 49u8,
+    175u8, 176u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 177u8, 49u8, 49u8, 49u8, 49u8, 178u8, 179u8, 49u8,
+    49u8, 49u8, 49u8, 49u8, 180u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 181u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 182u8, 183u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 184u8, 185u8, 2u8,
+    2u8, 186u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 187u8, 188u8, 189u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 190u8, 2u8, 171u8, 2u8, 170u8, 2u8, 2u8, 2u8, 2u8, 2u8, 191u8, 192u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 192u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 193u8, 2u8, 194u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 195u8, 2u8, 2u8, 196u8, 2u8, 2u8,
+    197u8, 198u8, 199u8, 200u8, 2u8, 2u8, 201u8, 202u8, 203u8, 204u8, 205u8, 206u8, 49u8, 207u8,
+    208u8, 209u8, 210u8, 211u8, 49u8, 212u8, 49u8, 213u8, 2u8, 2u8, 2u8, 214u8, 2u8, 2u8, 2u8, 2u8,
+    215u8, 216u8, 49u8, 49u8, 2u8, 217u8, 218u8, 219u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 220u8, 97u8, 97u8, 97u8, 97u8, 97u8,
+    97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8,
+    97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8,
+    97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8,
+    97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8,
+    97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8,
+    97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8,
+    97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8,
+    97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 97u8, 221u8, 168u8, 168u8, 168u8, 168u8,
+    168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8,
+    168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8,
+    168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8,
+    168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8,
+    168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8,
+    168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8,
+    168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8,
+    168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8,
+    168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 168u8,
+    168u8, 168u8, 168u8, 168u8, 168u8, 168u8, 220u8, 222u8, 3u8, 2u8, 2u8, 3u8, 3u8, 3u8, 4u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+    2u8, 2u8, 2u8, 2u8, 2u8,
+];
+pub static TABLE_2: [u8; 3568usize] = [
+    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 21u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 0u8,
+    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 85u8, 85u8, 85u8, 85u8, 21u8, 0u8, 80u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 1u8,
+    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 65u8, 16u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 0u8, 80u8, 85u8, 85u8, 0u8, 0u8,
+    64u8, 84u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 21u8, 0u8, 0u8, 0u8,
+    0u8, 0u8, 85u8, 85u8, 85u8, 85u8, 84u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 5u8,
+    0u8, 16u8, 0u8, 20u8, 4u8, 80u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 21u8, 81u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 64u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 5u8, 0u8, 0u8, 84u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 21u8, 0u8, 0u8, 85u8, 85u8, 81u8, 85u8, 85u8, 85u8, 85u8, 85u8, 5u8, 16u8,
+    0u8, 0u8, 1u8, 1u8, 80u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 1u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 80u8, 85u8, 0u8,
+    0u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 64u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 69u8, 84u8, 1u8, 0u8, 84u8, 81u8, 1u8, 0u8, 85u8, 85u8, 5u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 81u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 68u8, 1u8, 84u8, 85u8, 81u8, 85u8, 21u8, 85u8, 85u8, 5u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 69u8, 65u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 84u8, 65u8, 21u8, 20u8, 80u8, 81u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 80u8, 81u8, 85u8, 85u8, 1u8, 16u8, 84u8, 81u8, 85u8, 85u8, 85u8, 85u8, 5u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 5u8, 0u8, 81u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 4u8, 1u8, 84u8, 85u8, 81u8, 85u8, 1u8, 85u8, 85u8, 5u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 69u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 69u8, 84u8, 85u8, 85u8, 81u8, 85u8, 21u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 84u8, 84u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 4u8, 84u8, 5u8, 4u8, 80u8, 85u8, 65u8, 85u8, 85u8, 5u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 81u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 20u8, 69u8, 69u8, 85u8, 80u8, 85u8, 65u8, 85u8, 85u8, 5u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 80u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 21u8, 68u8, 1u8, 84u8, 85u8, 81u8, 85u8, 21u8, 85u8, 85u8, 5u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 81u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 69u8, 21u8, 5u8, 68u8, 85u8, 21u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 81u8, 0u8, 64u8, 85u8, 85u8, 21u8, 0u8, 64u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 81u8, 0u8, 0u8, 84u8, 85u8, 85u8, 0u8, 64u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 80u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 17u8, 81u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 1u8, 0u8, 0u8, 64u8, 0u8, 4u8, 85u8, 1u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+    0u8, 0u8, 0u8, 0u8, 84u8, 85u8, 69u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 21u8,
+    0u8, 4u8, 0u8, 64u8, 65u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 80u8, 5u8, 4u8, 20u8, 0u8, 80u8,
+    1u8, 84u8, 85u8, 85u8, 5u8, 1u8, 0u8, 16u8, 85u8, 85u8, 5u8, 80u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8,
+    170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8,
+    170u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 1u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 5u8, 84u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 5u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 5u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 5u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 16u8, 0u8, 80u8, 85u8, 69u8, 1u8, 0u8, 0u8, 85u8, 85u8, 81u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 21u8, 0u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 65u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 81u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    64u8, 21u8, 84u8, 85u8, 69u8, 85u8, 1u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 21u8, 20u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 69u8, 0u8, 64u8,
+    0u8, 0u8, 0u8, 84u8, 21u8, 0u8, 0u8, 20u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 64u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 0u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 0u8, 64u8, 84u8, 69u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 21u8, 0u8, 0u8, 85u8, 85u8, 85u8, 80u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 5u8,
+    80u8, 16u8, 80u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    69u8, 80u8, 17u8, 80u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 0u8, 0u8, 5u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 64u8, 0u8, 0u8, 0u8, 4u8, 0u8,
+    84u8, 81u8, 85u8, 84u8, 80u8, 85u8, 85u8, 85u8, 21u8, 0u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    0u8, 64u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 0u8, 4u8,
+    0u8, 0u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+    0u8, 84u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 165u8, 85u8, 85u8, 85u8,
+    105u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 169u8, 86u8, 150u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 105u8, 85u8, 85u8, 85u8, 85u8, 85u8, 90u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 170u8, 170u8, 170u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 149u8, 85u8, 85u8, 85u8, 85u8, 149u8,
+    85u8, 85u8, 85u8, 89u8, 85u8, 165u8, 85u8, 85u8, 85u8, 85u8, 105u8, 85u8, 90u8, 85u8, 101u8,
+    85u8, 86u8, 85u8, 85u8, 85u8, 85u8, 101u8, 85u8, 165u8, 89u8, 101u8, 89u8, 85u8, 89u8, 165u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 86u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    102u8, 149u8, 154u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 169u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 86u8, 85u8, 85u8, 149u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 149u8, 86u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 86u8, 89u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 21u8, 80u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 170u8,
+    170u8, 170u8, 170u8, 170u8, 170u8, 154u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8,
+    170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8,
+    170u8, 170u8, 85u8, 85u8, 85u8, 170u8, 170u8, 170u8, 170u8, 170u8, 90u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 170u8, 170u8, 170u8, 85u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8,
+    170u8, 170u8, 170u8, 10u8, 0u8, 170u8, 170u8, 170u8, 106u8, 169u8, 170u8, 170u8, 170u8, 170u8,
+    170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8,
+    170u8, 170u8, 170u8, 106u8, 129u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8,
+    170u8, 85u8, 169u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8,
+    169u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 106u8, 170u8, 170u8, 170u8, 170u8, 170u8,
+    170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8,
+    170u8, 170u8, 170u8, 85u8, 85u8, 85u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8,
+    170u8, 170u8, 170u8, 106u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8,
+    170u8, 85u8, 85u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8,
+    170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8,
+    170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 86u8, 170u8, 170u8, 170u8, 170u8, 170u8,
+    170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 106u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 21u8, 64u8, 0u8, 0u8, 80u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    5u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 80u8, 85u8, 85u8, 85u8, 69u8, 69u8, 21u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 65u8, 85u8, 84u8, 85u8, 85u8, 85u8, 85u8, 85u8, 80u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 0u8, 0u8, 0u8, 0u8, 80u8, 85u8, 85u8, 21u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 5u8, 0u8, 80u8, 85u8, 85u8, 85u8, 85u8, 85u8, 21u8, 0u8, 0u8, 80u8, 85u8, 85u8,
+    85u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 86u8, 64u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 21u8, 5u8, 80u8, 80u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 81u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 1u8, 64u8, 65u8, 65u8, 85u8, 85u8, 21u8, 85u8, 85u8, 84u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 21u8, 80u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 4u8, 20u8, 84u8, 5u8, 81u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 80u8, 85u8, 69u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 81u8, 84u8, 81u8, 85u8, 85u8, 85u8, 85u8, 170u8, 170u8, 170u8, 170u8,
+    170u8, 170u8, 170u8, 170u8, 170u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 69u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 85u8, 85u8, 85u8, 85u8, 0u8, 0u8, 0u8, 0u8,
+    170u8, 170u8, 90u8, 85u8, 0u8, 0u8, 0u8, 0u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8,
+    170u8, 106u8, 170u8, 170u8, 170u8, 170u8, 106u8, 170u8, 85u8, 85u8, 85u8, 85u8, 85u8, 170u8,
+    170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 86u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 170u8, 106u8, 85u8, 85u8, 85u8, 85u8,
+    1u8, 5u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 81u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 84u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 5u8,
+    64u8, 85u8, 1u8, 65u8, 85u8, 0u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    64u8, 21u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 65u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 0u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 21u8, 84u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 1u8, 85u8, 5u8, 0u8, 0u8, 84u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 5u8, 80u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 81u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    0u8, 0u8, 0u8, 64u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 20u8, 84u8,
+    85u8, 21u8, 80u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 21u8, 64u8,
+    65u8, 81u8, 69u8, 85u8, 85u8, 81u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 64u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 21u8, 0u8, 1u8, 0u8, 84u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 21u8, 85u8,
+    85u8, 85u8, 80u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 5u8,
+    0u8, 64u8, 85u8, 85u8, 1u8, 20u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 21u8, 80u8, 4u8,
+    85u8, 69u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 21u8, 21u8, 0u8, 64u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 84u8, 85u8, 85u8, 85u8, 85u8, 21u8, 85u8, 85u8, 85u8, 5u8, 0u8, 84u8, 0u8, 84u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    0u8, 0u8, 5u8, 68u8, 85u8, 85u8, 85u8, 85u8, 85u8, 69u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 20u8, 0u8,
+    68u8, 17u8, 4u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 21u8, 5u8, 80u8,
+    85u8, 16u8, 84u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 80u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 21u8, 0u8,
+    64u8, 17u8, 84u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 21u8, 81u8, 0u8, 16u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 1u8, 0u8, 16u8, 0u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 21u8, 0u8, 0u8,
+    65u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 84u8, 85u8,
+    21u8, 68u8, 21u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 0u8, 5u8, 85u8, 84u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 1u8, 0u8, 64u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 21u8, 0u8,
+    20u8, 64u8, 85u8, 21u8, 85u8, 85u8, 1u8, 64u8, 1u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 5u8, 0u8, 0u8, 64u8, 80u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 0u8, 64u8,
+    0u8, 16u8, 85u8, 85u8, 85u8, 85u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 4u8, 65u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 1u8, 64u8, 69u8,
+    16u8, 0u8, 16u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 80u8, 17u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 21u8, 84u8, 85u8,
+    85u8, 80u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 5u8, 64u8,
+    85u8, 68u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 84u8, 21u8, 0u8, 0u8, 0u8, 80u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 0u8, 84u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 0u8, 64u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 21u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 21u8, 64u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 170u8, 84u8, 85u8, 85u8, 90u8, 85u8,
+    85u8, 85u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8,
+    170u8, 170u8, 85u8, 85u8, 170u8, 170u8, 170u8, 170u8, 170u8, 90u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 170u8, 170u8, 86u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 170u8, 169u8, 170u8, 105u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8,
+    170u8, 106u8, 85u8, 85u8, 85u8, 101u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 106u8, 89u8,
+    85u8, 85u8, 85u8, 170u8, 85u8, 85u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8,
+    170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 65u8, 0u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 0u8, 0u8,
+    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 80u8, 0u8, 0u8, 0u8, 0u8, 0u8, 64u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 17u8, 80u8, 5u8, 0u8, 0u8, 0u8, 0u8, 64u8, 1u8, 0u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 5u8, 80u8, 85u8, 85u8, 85u8, 85u8, 5u8, 84u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 64u8, 21u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+    0u8, 0u8, 0u8, 84u8, 85u8, 81u8, 85u8, 85u8, 85u8, 84u8, 85u8, 85u8, 85u8, 85u8, 21u8, 0u8,
+    1u8, 0u8, 0u8, 0u8, 85u8, 85u8, 85u8, 85u8, 0u8, 64u8, 0u8, 0u8, 0u8, 0u8, 20u8, 0u8, 16u8,
+    4u8, 64u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 69u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 0u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 0u8, 64u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 0u8, 64u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 86u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 149u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 101u8, 169u8, 170u8, 106u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 165u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 106u8, 85u8, 85u8, 85u8, 170u8,
+    170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 85u8, 170u8, 170u8, 86u8,
+    85u8, 90u8, 85u8, 85u8, 85u8, 170u8, 90u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 170u8, 170u8,
+    170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 86u8, 85u8, 85u8, 169u8, 170u8, 154u8, 170u8, 170u8,
+    170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8,
+    170u8, 170u8, 166u8, 170u8, 170u8, 170u8, 170u8, 170u8, 85u8, 85u8, 85u8, 170u8, 170u8, 170u8,
+    170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 106u8, 149u8, 170u8, 85u8, 85u8, 85u8, 170u8,
+    170u8, 170u8, 170u8, 86u8, 86u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8,
+    170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 106u8, 166u8, 170u8, 170u8, 170u8,
+    170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8,
+    170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8,
+    170u8, 150u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8,
+    170u8, 170u8, 170u8, 170u8, 90u8, 85u8, 85u8, 149u8, 106u8, 170u8, 170u8, 170u8, 170u8, 170u8,
+    170u8, 85u8, 85u8, 85u8, 85u8, 101u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 105u8, 85u8, 85u8,
+    85u8, 86u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 149u8, 170u8, 170u8, 170u8, 170u8, 170u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 170u8, 90u8, 85u8, 86u8, 106u8, 169u8,
+    85u8, 170u8, 85u8, 85u8, 149u8, 86u8, 85u8, 170u8, 170u8, 86u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 170u8, 170u8, 170u8, 85u8, 86u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 170u8,
+    170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 106u8, 170u8, 170u8,
+    154u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8,
+    170u8, 170u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 170u8,
+    170u8, 170u8, 86u8, 170u8, 170u8, 86u8, 85u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8,
+    170u8, 170u8, 170u8, 170u8, 154u8, 170u8, 90u8, 85u8, 165u8, 170u8, 170u8, 170u8, 85u8, 170u8,
+    170u8, 86u8, 85u8, 170u8, 170u8, 86u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 85u8, 5u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8,
+    170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 170u8, 10u8, 81u8, 85u8, 85u8, 85u8, 85u8,
+    85u8, 85u8, 85u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+];
+#[doc = r" Looks up the width of a codepoint in the width tables."]
+#[doc = r""]
+#[doc = r" The bits of the codepoint are split into one index per table."]
+#[doc = r" Each index selects an entry in the sub-table chosen by the previous level."]
+#[doc = r" The last level packs multiple widths into each byte."]
+#[inline]
+pub fn lookup_width(c: char) -> u8 {
+    let cp = c as usize;
+    let offset_0 = TABLE_0[cp >> 13 & 255];
+    let offset_1 = TABLE_1[128 * usize::from(offset_0) + (cp >> 6 & 127)];
+    let packed_widths = TABLE_2[16 * usize::from(offset_1) + (cp >> 2 & 15)];
+    packed_widths >> (2 * (cp & 3)) & 3
+}
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 xflags::xflags! {
     src "./src/flags.rs"
 
@@ -10,7 +12,35 @@ xflags::xflags! {
 
         cmd gen-tables
         required unicode_version: String
-        {}
+        {
+            /// Bit offsets (separated by commas) at which codepoints are split into
+            /// table levels, defaults to `13,6,0`.
+            optional --split split: String
+            /// File with `codepoint;width` lines that override the computed widths.
+            optional --overrides overrides: PathBuf
+            /// Read the UCD files from a local `UCD.zip` archive instead of downloading them.
+            optional --ucd-zip ucd_zip: PathBuf
+            /// Use `DerivedAge.txt` to treat codepoints that were assigned after
+            /// `unicode_version` as unassigned (for example when reading a newer `UCD.zip`).
+            optional --age-accurate
+        }
+
+        /// Print the uncompressed width of every codepoint as ranges.
+        cmd dump-widths
+        required unicode_version: String
+        {
+            /// Print the ranges as a JSON array instead of `codepoints;width` lines.
+            optional --json
+            /// Read the UCD files from a local `UCD.zip` archive instead of downloading them.
+            optional --ucd-zip ucd_zip: PathBuf
+        }
+
+        /// Compare the widths of `grapheme_width` against the `unicode-width` crate.
+        cmd validate {
+            /// Compare against a file with `codepoints;width` lines (in the same format
+            /// as printed by `dump-widths`) instead of the `unicode-width` crate.
+            optional --reference reference: PathBuf
+        }
 
     }
 }
@@ -26,6 +56,8 @@ pub struct Xtask {
 pub enum XtaskCmd {
     Help(Help),
     GenTables(GenTables),
+    DumpWidths(DumpWidths),
+    Validate(Validate),
 }
 
 #[derive(Debug)]
@@ -36,6 +68,24 @@ pub struct Help {
 #[derive(Debug)]
 pub struct GenTables {
     pub unicode_version: String,
+
+    pub split: Option<String>,
+    pub overrides: Option<PathBuf>,
+    pub ucd_zip: Option<PathBuf>,
+    pub age_accurate: bool,
+}
+
+#[derive(Debug)]
+pub struct DumpWidths {
+    pub unicode_version: String,
+
+    pub json: bool,
+    pub ucd_zip: Option<PathBuf>,
+}
+
+#[derive(Debug)]
+pub struct Validate {
+    pub reference: Option<PathBuf>,
 }
 
 impl Xtask {
@@ -1,8 +1,10 @@
 //! See <https://github.com/matklad/cargo-xtask/>.
 //! This binary is integrated into the `cargo` command line by using an alias in
 //! `.cargo/config`.
+#[allow(dead_code)]
 mod flags;
 mod tables;
+mod validate;
 
 use std::env;
 use std::path::{Path, PathBuf};
@@ -21,6 +23,8 @@ fn main() -> Result<()> {
             Ok(())
         }
         flags::XtaskCmd::GenTables(cmd) => cmd.run(&sh),
+        flags::XtaskCmd::DumpWidths(cmd) => cmd.run(),
+        flags::XtaskCmd::Validate(cmd) => cmd.run(&sh),
     }
 }
 
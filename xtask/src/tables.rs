@@ -1,25 +1,102 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write;
+use std::fs::{self, File};
+use std::io::Read;
 use std::mem::swap;
 use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use anyhow::{bail, Context, Result};
-use proc_macro2::TokenStream;
+use proc_macro2::{Literal, TokenStream};
 use quote::{format_ident, quote, ToTokens};
+use sha2::{Digest, Sha256};
 use ucd_trie::{TrieSetOwned, TrieSetSlice};
 use xshell::Shell;
+use zip::ZipArchive;
 
-use crate::flags::GenTables;
+use crate::flags::{DumpWidths, GenTables};
 use crate::reformat;
 
-const NUM_CODEPOINTS: u32 = 0x110000;
+pub(crate) const NUM_CODEPOINTS: u32 = 0x110000;
 const MAX_CODEPOINT_BITS: u32 = u32::BITS - (NUM_CODEPOINTS - 1).leading_zeros();
 type UncompressedTable = [u8; NUM_CODEPOINTS as usize];
 
-fn retrieve_file(version: &str, file: &str) -> Result<String> {
-    let url = format!("http://unicode.org/Public/{version}/ucd/{file}.txt");
+/// The source that the UCD files are retrieved from.
+enum UcdSource {
+    /// Download the files of the given unicode version from unicode.org
+    Download(String),
+    /// Read the files from a local `UCD.zip` archive as published by unicode.org
+    Zip(PathBuf),
+}
+
+impl UcdSource {
+    fn retrieve_file(&self, file: &str) -> Result<String> {
+        match self {
+            UcdSource::Download(version) => download(&format!(
+                "http://unicode.org/Public/{version}/ucd/{file}.txt"
+            )),
+            UcdSource::Zip(archive) => {
+                let entry = format!("{file}.txt");
+                println!("extracting {entry} from {}...", archive.display());
+                read_zip_entry(archive, &entry)
+                    .with_context(|| format!("failed to read {entry} from {}", archive.display()))
+            }
+        }
+    }
+
+    /// Retrieves one of the emoji data files that are published separately
+    /// from the UCD (like `emoji-zwj-sequences.txt`). These files are not part
+    /// of `UCD.zip` and must be added to the `emoji` directory of the archive.
+    fn retrieve_emoji_file(&self, file: &str) -> Result<String> {
+        match self {
+            UcdSource::Download(version) => download(&format!(
+                "http://unicode.org/Public/emoji/{}/{file}.txt",
+                emoji_version(version)
+            )),
+            UcdSource::Zip(_) => self.retrieve_file(&format!("emoji/{file}")),
+        }
+    }
+
+    /// Describes where the files were retrieved from. This is recorded in the
+    /// header of the generated files so that the tables can be reproduced.
+    fn describe(&self) -> Result<String> {
+        match self {
+            UcdSource::Download(version) => Ok(format!(
+                "http://unicode.org/Public/{version}/ucd/ and http://unicode.org/Public/emoji/{}/",
+                emoji_version(version)
+            )),
+            UcdSource::Zip(archive) => {
+                let contents = fs::read(archive)
+                    .with_context(|| format!("failed to read {}", archive.display()))?;
+                let name = archive.file_name().unwrap_or(archive.as_os_str());
+                Ok(format!(
+                    "local archive {} (SHA-256 {:x})",
+                    name.to_string_lossy(),
+                    Sha256::digest(&contents)
+                ))
+            }
+        }
+    }
+}
+
+/// The emoji data files are versioned without the patch version (`15.0` for UCD `15.0.0`).
+fn emoji_version(version: &str) -> &str {
+    version
+        .rsplit_once('.')
+        .map_or(version, |(emoji_version, _)| emoji_version)
+}
+
+fn read_zip_entry(archive: &Path, entry: &str) -> Result<String> {
+    let mut archive = ZipArchive::new(File::open(archive)?)?;
+    let mut contents = String::new();
+    archive.by_name(entry)?.read_to_string(&mut contents)?;
+    Ok(contents)
+}
+
+fn download(url: &str) -> Result<String> {
     println!("downloading {url}...");
-    ureq::get(&url)
+    ureq::get(url)
         .call()?
         .into_string()
         .context("download failed")
@@ -29,7 +106,7 @@ fn parse_codepoint(s: &str) -> Result<usize> {
     usize::from_str_radix(s, 16).context("failed to parse codepoint")
 }
 
-fn parse_codepoints(s: &str) -> anyhow::Result<RangeInclusive<usize>> {
+pub(crate) fn parse_codepoints(s: &str) -> anyhow::Result<RangeInclusive<usize>> {
     let (start, end) = match s.split_once("..") {
         Some(range) => range,
         None => (s, s),
@@ -39,7 +116,7 @@ fn parse_codepoints(s: &str) -> anyhow::Result<RangeInclusive<usize>> {
     Ok(start..=end)
 }
 
-fn parse_data_line(mut line: &str) -> Option<Vec<&str>> {
+pub(crate) fn parse_data_line(mut line: &str) -> Option<Vec<&str>> {
     line = line.trim();
     if line.starts_with('#') || line.is_empty() {
         return None;
@@ -58,19 +135,59 @@ struct RawUnicodeData {
     /// Contents of emoji-variants.txt used to retrieve emojis whose presentation
     /// and width is determined by a variant selector
     emoji_variants: String,
+    /// Contents of emoji-zwj-sequences.txt used to retrieve the RGI emoji ZWJ sequences
+    emoji_zwj_sequences: String,
+    /// Contents of GraphemeBreakProperty.txt used to retrieve spacing combining marks
+    /// and the grapheme cluster break classes
+    grapheme_break: String,
+    /// Contents of DerivedAge.txt and the (major, minor) unicode version, codepoints
+    /// that were assigned in a later version are treated as unassigned
+    age_limit: Option<(String, (u32, u32))>,
+}
+
+/// Codepoints that default to "W" (see `fill_table_with_eaw_width`) even if they are unassigned
+const DEFAULT_WIDE_RANGES: [RangeInclusive<usize>; 5] = [
+    0x3400..=0x4DBF,
+    0x4E00..=0x9FFF,
+    0xF900..=0xFAFF,
+    0x20000..=0x2FFFD,
+    0x30000..=0x3FFFD,
+];
+
+/// The precomposed hangul syllables, the grapheme break class of the `LV` syllables
+/// (every 28th codepoint) and `LVT` syllables is not included in the generated table.
+const HANGUL_SYLLABLES: RangeInclusive<usize> = 0xAC00..=0xD7A3;
+
+/// Parses the major and minor version from a unicode version like `15.0.0` or `15.0`.
+fn parse_major_minor(version: &str) -> Result<(u32, u32)> {
+    let mut parts = version.split('.');
+    let (Some(major), Some(minor)) = (parts.next(), parts.next()) else {
+        bail!("invalid unicode version {version:?}, expected `major.minor`")
+    };
+    Ok((major.parse()?, minor.parse()?))
 }
 
 impl RawUnicodeData {
-    pub fn new(version: &str) -> Result<RawUnicodeData> {
+    pub fn new(source: &UcdSource) -> Result<RawUnicodeData> {
         let data = RawUnicodeData {
-            unicode_data: retrieve_file(version, "UnicodeData")?,
-            eaw_data: retrieve_file(version, "EastAsianWidth")?,
-            emoji_data: retrieve_file(version, "emoji/emoji-data")?,
-            emoji_variants: retrieve_file(version, "emoji/emoji-variation-sequences")?,
+            unicode_data: source.retrieve_file("UnicodeData")?,
+            eaw_data: source.retrieve_file("EastAsianWidth")?,
+            emoji_data: source.retrieve_file("emoji/emoji-data")?,
+            emoji_variants: source.retrieve_file("emoji/emoji-variation-sequences")?,
+            emoji_zwj_sequences: source.retrieve_emoji_file("emoji-zwj-sequences")?,
+            grapheme_break: source.retrieve_file("auxiliary/GraphemeBreakProperty")?,
+            age_limit: None,
         };
         Ok(data)
     }
 
+    /// Loads DerivedAge.txt so that codepoints assigned after `version` are treated as unassigned.
+    pub fn limit_age(&mut self, source: &UcdSource, version: &str) -> Result<()> {
+        let derived_age = source.retrieve_file("DerivedAge")?;
+        self.age_limit = Some((derived_age, parse_major_minor(version)?));
+        Ok(())
+    }
+
     fn codepoint_data(&self) -> Result<CodePointData> {
         println!("calculating codepoint widths...");
         let mut table: Box<UncompressedTable> =
@@ -78,14 +195,59 @@ impl RawUnicodeData {
         self.fill_table_with_eaw_width(&mut table)?;
         self.fill_zero_width_categories(&mut table)?;
         self.fill_emojis(&mut table)?;
+        let mut emoji_variations = self.emoji_variations()?;
+        let mut emoji_presentation = self.emoji_presentation()?;
+        let mut emoji_zwj_sequences = self.emoji_zwj_sequences()?;
+        let mut ambiguous = self.ambiguous_width()?;
+        let mut grapheme_break = self.grapheme_break()?;
+        let mut unassigned = self.unassigned()?;
+        let newer = self.assigned_after_age_limit()?;
+        if !newer.is_empty() {
+            println!("treating {} newer codepoints as unassigned...", newer.len());
+            for &codepoint in &newer {
+                let wide = DEFAULT_WIDE_RANGES
+                    .iter()
+                    .any(|range| range.contains(&(codepoint as usize)));
+                table[codepoint as usize] = if wide { 2 } else { 1 };
+                emoji_variations.remove(&codepoint);
+                emoji_presentation.remove(&codepoint);
+                ambiguous.remove(&codepoint);
+                // Extended_Pictographic also covers reserved codepoints and is kept
+                if grapheme_break.get(&codepoint) != Some(&"ExtendedPictographic") {
+                    grapheme_break.remove(&codepoint);
+                }
+            }
+            unassigned.extend(&newer);
+            emoji_zwj_sequences
+                .retain(|sequence| !sequence.chars().any(|c| newer.contains(&(c as u32))));
+        }
         Self::fill_hardcoded_widths(&mut table);
-        let emoji_variations = self.emoji_variations()?;
         Ok(CodePointData {
             widths: table,
+            ambiguous,
+            grapheme_break,
+            unassigned,
             emoji_variations,
+            emoji_presentation,
+            emoji_zwj_sequences,
         })
     }
 
+    /// Returns the codepoints that were assigned in a later version than the age limit.
+    fn assigned_after_age_limit(&self) -> Result<HashSet<u32>> {
+        let mut newer = HashSet::new();
+        let Some((derived_age, limit)) = &self.age_limit else { return Ok(newer) };
+        for line in derived_age.lines() {
+            let Some(fields) = parse_data_line(line) else { continue };
+            let [codepoints, age] = fields.as_slice() else { bail!("invalid derived age line {line}") };
+            if parse_major_minor(age)? > *limit {
+                let codepoints = parse_codepoints(codepoints)?;
+                newer.extend(codepoints.map(|codepoint| codepoint as u32));
+            }
+        }
+        Ok(newer)
+    }
+
     fn fill_hardcoded_widths(table: &mut UncompressedTable) {
         // hardcoded zero width chars: surrage pairs and private ranges count here
         let mut zerow_width_ranges = vec![
@@ -111,6 +273,105 @@ impl RawUnicodeData {
         table[0x00AD] = 1;
     }
 
+    fn ambiguous_width(&self) -> Result<HashSet<u32>> {
+        let mut ambiguous = HashSet::with_capacity(1 << 18);
+        for line in self.eaw_data.lines() {
+            let Some(fields) = parse_data_line(line) else { continue };
+            let [codepoints, "A"] = fields.as_slice() else { continue };
+            let codepoints = parse_codepoints(codepoints)?;
+            ambiguous.extend(codepoints.map(|codepoint| codepoint as u32));
+        }
+        Ok(ambiguous)
+    }
+
+    /// Returns all codepoints with `Grapheme_Cluster_Break=SpacingMark`.
+    fn spacing_marks(&self) -> Result<HashSet<u32>> {
+        let mut spacing_marks = HashSet::new();
+        for line in self.grapheme_break.lines() {
+            let Some(fields) = parse_data_line(line) else { continue };
+            let [codepoints, "SpacingMark"] = fields.as_slice() else { continue };
+            let codepoints = parse_codepoints(codepoints)?;
+            spacing_marks.extend(codepoints.map(|codepoint| codepoint as u32));
+        }
+        Ok(spacing_marks)
+    }
+
+    /// Returns the `Grapheme_Cluster_Break` class of all codepoints that are not `Other`
+    /// as the name of the `GraphemeBreak` variant. `Extended_Pictographic` (from emoji-data.txt)
+    /// is treated as an additional class.
+    ///
+    /// The `LV` and `LVT` hangul syllables are omitted as they are computed
+    /// arithmetically by `grapheme_break` instead of looking them up.
+    fn grapheme_break(&self) -> Result<BTreeMap<u32, &'static str>> {
+        let mut classes = BTreeMap::new();
+        for line in self.grapheme_break.lines() {
+            let Some(fields) = parse_data_line(line) else { continue };
+            let [codepoints, class] = fields.as_slice() else { bail!("invalid grapheme break line {line}") };
+            let codepoints = parse_codepoints(codepoints)?;
+            let variant = match *class {
+                "CR" => "Cr",
+                "LF" => "Lf",
+                "Control" => "Control",
+                "Extend" => "Extend",
+                "ZWJ" => "Zwj",
+                "Regional_Indicator" => "RegionalIndicator",
+                "Prepend" => "Prepend",
+                "SpacingMark" => "SpacingMark",
+                "L" => "L",
+                "V" => "V",
+                "T" => "T",
+                "LV" | "LVT" => {
+                    for codepoint in codepoints {
+                        let lv = (codepoint - HANGUL_SYLLABLES.start()).is_multiple_of(28);
+                        if !HANGUL_SYLLABLES.contains(&codepoint) || lv != (*class == "LV") {
+                            bail!("unexpected hangul syllable class {class} for {codepoint:04X}");
+                        }
+                    }
+                    continue;
+                }
+                _ => bail!("unknown grapheme break class {class}"),
+            };
+            classes.extend(codepoints.map(|codepoint| (codepoint as u32, variant)));
+        }
+        for line in self.emoji_data.lines() {
+            let Some(fields) = parse_data_line(line) else { continue };
+            let [codepoints, "Extended_Pictographic", ..] = fields.as_slice() else { continue };
+            for codepoint in parse_codepoints(codepoints)? {
+                if let Some(class) = classes.insert(codepoint as u32, "ExtendedPictographic") {
+                    bail!("Extended_Pictographic {codepoint:04X} also has grapheme break class {class}");
+                }
+            }
+        }
+        Ok(classes)
+    }
+
+    /// Returns all codepoints with general category `Cn`, which are not listed in UnicodeData.txt.
+    /// Large blocks like CJK ideographs are listed as a `<..., First>` and `<..., Last>` pair.
+    fn unassigned(&self) -> Result<HashSet<u32>> {
+        let mut unassigned: HashSet<u32> = (0..NUM_CODEPOINTS).collect();
+        let mut range_start = None;
+        for line in self.unicode_data.lines() {
+            let Some(fields) = parse_data_line(line) else { continue };
+            let [codepoint, name, ..] = fields.as_slice() else { bail!("invalid unicode data line {line}") };
+            let codepoint = parse_codepoint(codepoint)? as u32;
+            if name.ends_with(", First>") {
+                range_start = Some(codepoint);
+                continue;
+            }
+            let start = if name.ends_with(", Last>") {
+                range_start
+                    .take()
+                    .context("range end without start in unicode data")?
+            } else {
+                codepoint
+            };
+            for codepoint in start..=codepoint {
+                unassigned.remove(&codepoint);
+            }
+        }
+        Ok(unassigned)
+    }
+
     fn fill_table_with_eaw_width(&self, table: &mut UncompressedTable) -> Result<()> {
         for line in self.eaw_data.lines() {
             let Some(fields) = parse_data_line(line) else { continue };
@@ -129,14 +390,7 @@ impl RawUnicodeData {
         //      outside of allocated blocks, default to "W":
         //         Plane 2:                            U+20000..U+2FFFD
         //         Plane 3:                            U+30000..U+3FFFD
-        let wide_ranges = [
-            0x3400..=0x4DBF,
-            0x4E00..=0x9FFF,
-            0xF900..=0xFAFF,
-            0x20000..=0x2FFFD,
-            0x30000..=0x3FFFD,
-        ];
-        for wide_range in wide_ranges {
+        for wide_range in DEFAULT_WIDE_RANGES {
             for code_point in wide_range {
                 if table[code_point] == u8::MAX {
                     table[code_point] = 2
@@ -147,15 +401,26 @@ impl RawUnicodeData {
     }
 
     fn fill_zero_width_categories(&self, table: &mut UncompressedTable) -> Result<()> {
+        // Spacing combining marks (Mc) that are `Grapheme_Cluster_Break=SpacingMark`
+        // (for example most indic vowel signs) occupy their own cell in terminals,
+        // so they keep their east asian width. They still extend the preceding grapheme.
+        // The remaining Mc marks are `Grapheme_Cluster_Break=Extend` (for example
+        // BENGALI VOWEL SIGN AA or the musical stems) and are zero width like `Mn`.
+        let spacing_marks = self.spacing_marks()?;
         for line in self.unicode_data.lines() {
             let Some(fields) = parse_data_line(line) else { continue };
             let [codepoints, _, category, ..] = fields.as_slice() else {continue;};
             let codepoints = parse_codepoints(codepoints)?;
-            if matches!(
-                *category,
-                "Cc" | "Cf" | "Zl" | "Zp" | "Cs" | "Mn" | "Mc" | "Me"
-            ) {
-                table[codepoints].fill(0)
+            match *category {
+                "Cc" | "Cf" | "Zl" | "Zp" | "Cs" | "Mn" | "Me" => table[codepoints].fill(0),
+                "Mc" => {
+                    for codepoint in codepoints {
+                        if !spacing_marks.contains(&(codepoint as u32)) {
+                            table[codepoint] = 0
+                        }
+                    }
+                }
+                _ => (),
             }
         }
 
@@ -175,6 +440,19 @@ impl RawUnicodeData {
         Ok(())
     }
 
+    fn emoji_presentation(&self) -> Result<HashSet<u32>> {
+        let mut emoji_presentation = HashSet::with_capacity(2048);
+        for line in self.emoji_data.lines() {
+            let Some(fields) = parse_data_line(line) else { continue };
+            let [codepoints, prop, ..] = fields.as_slice() else {bail!("invalid emoji data line {line}");};
+            if *prop == "Emoji_Presentation" {
+                let codepoints = parse_codepoints(codepoints)?;
+                emoji_presentation.extend(codepoints.map(|codepoint| codepoint as u32));
+            }
+        }
+        Ok(emoji_presentation)
+    }
+
     fn emoji_variations(&self) -> Result<HashSet<u32>> {
         let mut emoji_variations = HashSet::with_capacity(1024);
         for line in self.emoji_variants.lines() {
@@ -186,49 +464,225 @@ impl RawUnicodeData {
         }
         Ok(emoji_variations)
     }
+
+    /// Returns the sorted RGI emoji ZWJ sequences with all VS16 removed
+    /// so that sequences are also recognized if VS16 is omitted.
+    fn emoji_zwj_sequences(&self) -> Result<Vec<String>> {
+        let mut sequences = Vec::with_capacity(2048);
+        for line in self.emoji_zwj_sequences.lines() {
+            let Some(fields) = parse_data_line(line) else { continue };
+            let [codepoints, "RGI_Emoji_ZWJ_Sequence", ..] = fields.as_slice() else {bail!("invalid emoji zwj sequences line {line}");};
+            let sequence: Result<String> = codepoints
+                .split(' ')
+                .map(parse_codepoint)
+                .filter(|codepoint| !matches!(codepoint, Ok(0xFE0F)))
+                .map(|codepoint| {
+                    char::from_u32(codepoint? as u32)
+                        .context("invalid codepoint in emoji zwj sequence")
+                })
+                .collect();
+            sequences.push(sequence?);
+        }
+        sequences.sort();
+        sequences.dedup();
+        Ok(sequences)
+    }
 }
 
 struct CodePointData {
     widths: Box<UncompressedTable>,
+    ambiguous: HashSet<u32>,
+    grapheme_break: BTreeMap<u32, &'static str>,
+    unassigned: HashSet<u32>,
     emoji_variations: HashSet<u32>,
+    emoji_presentation: HashSet<u32>,
+    emoji_zwj_sequences: Vec<String>,
+}
+
+/// An inclusive range of codepoints that all have the same width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct WidthRange {
+    start: u32,
+    end: u32,
+    width: u8,
+}
+
+/// Formats the ranges as `codepoints;width` lines, the same format accepted by `--overrides`.
+fn emit_width_ranges(ranges: &[WidthRange]) -> String {
+    let mut res = String::new();
+    for range in ranges {
+        if range.start == range.end {
+            writeln!(res, "{:04X};{}", range.start, range.width).unwrap();
+        } else {
+            writeln!(
+                res,
+                "{:04X}..{:04X};{}",
+                range.start, range.end, range.width
+            )
+            .unwrap();
+        }
+    }
+    res
+}
+
+/// Formats the ranges as a JSON array of `{"start":..,"end":..,"width":..}` objects.
+fn emit_width_ranges_json(ranges: &[WidthRange]) -> String {
+    let mut res = String::from("[\n");
+    for (i, range) in ranges.iter().enumerate() {
+        let separator = if i == ranges.len() - 1 { "" } else { "," };
+        writeln!(
+            res,
+            r#"  {{"start":{},"end":{},"width":{}}}{separator}"#,
+            range.start, range.end, range.width
+        )
+        .unwrap();
+    }
+    res.push_str("]\n");
+    res
+}
+
+/// The bit ranges `(low_bit, cap_bit)` of a codepoint used to index each level
+/// of the width lookup table. The last level always starts at bit 0 and packs
+/// multiple widths into a single byte.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct TableSplit {
+    levels: Vec<(u32, u32)>,
 }
 
-const TABLE_DEPTH: usize = 3;
-const TABLES: [(u32, u32); TABLE_DEPTH] = [(13, MAX_CODEPOINT_BITS), (6, 13), (0, 6)];
+impl TableSplit {
+    fn new(low_bits: &[u32]) -> Result<TableSplit> {
+        let mut cap_bit = MAX_CODEPOINT_BITS;
+        let mut levels = Vec::with_capacity(low_bits.len());
+        for &low_bit in low_bits {
+            if low_bit >= cap_bit {
+                bail!("table split {low_bits:?} must be strictly decreasing and below {MAX_CODEPOINT_BITS}");
+            }
+            levels.push((low_bit, cap_bit));
+            cap_bit = low_bit;
+        }
+        let min_packed_bits = (u8::BITS / BITS_PER_CODEPOINT as u32).ilog2();
+        match levels.last() {
+            Some(&(0, cap_bit)) if cap_bit >= min_packed_bits => Ok(TableSplit { levels }),
+            _ => bail!("the last level of table split {low_bits:?} must start at bit 0 and span at least {min_packed_bits} bits"),
+        }
+    }
+
+    fn depth(&self) -> usize {
+        self.levels.len()
+    }
+}
+
+impl Default for TableSplit {
+    fn default() -> TableSplit {
+        TableSplit::new(&[13, 6, 0]).unwrap()
+    }
+}
+
+impl FromStr for TableSplit {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<TableSplit> {
+        let low_bits: Result<Vec<u32>> = s
+            .split(',')
+            .map(|bit| {
+                bit.trim()
+                    .parse()
+                    .with_context(|| format!("invalid table split {s}"))
+            })
+            .collect();
+        TableSplit::new(&low_bits?)
+    }
+}
 
 impl CodePointData {
+    /// Applies the `codepoint;width` lines in `overrides` on top of the computed widths.
+    fn apply_width_overrides(&mut self, overrides: &str) -> Result<()> {
+        for line in overrides.lines() {
+            let Some(fields) = parse_data_line(line) else { continue };
+            let [codepoints, width] = fields.as_slice() else {
+                bail!("invalid width override {line:?}, expected `codepoint;width`")
+            };
+            let codepoints = parse_codepoints(codepoints)?;
+            if *codepoints.end() >= NUM_CODEPOINTS as usize {
+                bail!("width override {line:?} is outside the unicode codespace");
+            }
+            let width = match *width {
+                "0" => 0,
+                "1" => 1,
+                "2" => 2,
+                _ => bail!("invalid width in override {line:?}, must be 0, 1 or 2"),
+            };
+            self.widths[codepoints].fill(width);
+        }
+        Ok(())
+    }
+
+    /// The width of `codepoint`, codepoints not covered by the UCD default to 1.
+    fn width(&self, codepoint: u32) -> u8 {
+        match self.widths[codepoint as usize] {
+            u8::MAX => 1,
+            width => width,
+        }
+    }
+
+    /// Merges consecutive codepoints with the same width into ranges.
+    fn width_ranges(&self) -> Vec<WidthRange> {
+        let mut ranges: Vec<WidthRange> = Vec::new();
+        for codepoint in 0..NUM_CODEPOINTS {
+            let width = self.width(codepoint);
+            match ranges.last_mut() {
+                Some(range) if range.width == width => range.end = codepoint,
+                _ => ranges.push(WidthRange {
+                    start: codepoint,
+                    end: codepoint,
+                    width,
+                }),
+            }
+        }
+        ranges
+    }
+
     fn compress_emoji_variations(&self) -> TrieSetOwned {
         println!("Compressing emoji variations...");
         TrieSetOwned::from_codepoints(self.emoji_variations.iter()).unwrap()
     }
 
-    fn compress_widths(&self) -> [Table; TABLE_DEPTH] {
-        let widths: Vec<_> = self
-            .widths
+    fn compress_ambiguous(&self) -> TrieSetOwned {
+        println!("Compressing ambiguous width...");
+        TrieSetOwned::from_codepoints(self.ambiguous.iter()).unwrap()
+    }
+
+    fn compress_unassigned(&self) -> TrieSetOwned {
+        println!("Compressing unassigned codepoints...");
+        TrieSetOwned::from_codepoints(self.unassigned.iter()).unwrap()
+    }
+
+    fn compress_emoji_presentation(&self) -> TrieSetOwned {
+        println!("Compressing emoji presentation...");
+        TrieSetOwned::from_codepoints(self.emoji_presentation.iter()).unwrap()
+    }
+
+    fn compress_widths(&self, split: &TableSplit) -> Vec<Table> {
+        let widths: Vec<_> = (0..NUM_CODEPOINTS)
+            .map(|codepoint| (codepoint, self.width(codepoint)))
+            .collect();
+        let mut codepoint_groups = vec![widths];
+        split
+            .levels
             .iter()
-            .copied()
             .enumerate()
-            .map(|(codepoint, mut width)| {
-                if width == u8::MAX {
-                    width = 1
-                }
-                (codepoint as u32, width)
+            .map(|(i, &(low_bit, cap_bit))| {
+                println!("Compressing width table (depth {i})...");
+                let table = Table::new(&codepoint_groups, low_bit, cap_bit);
+                println!("found {} unique subtables", table.buckets.len());
+                codepoint_groups = table
+                    .buckets
+                    .iter()
+                    .map(|bucket| bucket.codepoints())
+                    .collect();
+                table
             })
-            .collect();
-        let mut codepoint_groups = vec![widths];
-        let mut i = 0;
-        TABLES.map(|(low_bit, cap_bit)| {
-            println!("Compressing width table (depth {i})...");
-            let table = Table::new(&codepoint_groups, low_bit, cap_bit);
-            println!("found {} unique subtables", table.buckets.len());
-            codepoint_groups = table
-                .buckets
-                .iter()
-                .map(|bucket| bucket.codepoints())
-                .collect();
-            i += 1;
-            table
-        })
+            .collect()
     }
 }
 
@@ -285,10 +739,10 @@ impl Table {
             })
             .collect()
     }
-    fn into_bytes(self) -> Vec<u8> {
+    fn into_bytes(self) -> Result<Vec<u8>> {
         self.entries
             .iter()
-            .map(|&i| u8::try_from(i).unwrap())
+            .map(|&i| u8::try_from(i).context("too many subtables, choose a different table split"))
             .collect()
     }
 }
@@ -351,7 +805,25 @@ impl Bucket {
     }
 }
 
-fn emit_width_table(tables: [Table; TABLE_DEPTH], version: &str) -> Result<TokenStream> {
+/// Converts the compressed tables into the bytes emitted for each level.
+fn into_table_bytes(tables: Vec<Table>) -> Result<Vec<Vec<u8>>> {
+    let depth = tables.len();
+    tables
+        .into_iter()
+        .enumerate()
+        .map(|(i, table)| {
+            if i == depth - 1 {
+                Ok(table.into_flat_bytes())
+            } else {
+                table
+                    .into_bytes()
+                    .with_context(|| format!("failed to emit table level {i}"))
+            }
+        })
+        .collect()
+}
+
+fn emit_width_table(tables: Vec<Table>, split: &TableSplit, version: &str) -> Result<TokenStream> {
     let mut res = TokenStream::new();
     let version_components: Result<Vec<_>, _> =
         version.trim().split('.').map(u8::from_str).collect();
@@ -361,24 +833,71 @@ fn emit_width_table(tables: [Table; TABLE_DEPTH], version: &str) -> Result<Token
         pub const UNICODE_VERSION: (u8, u8, u8) = (#major, #minor, #patch);
     }
     .to_tokens(&mut res);
-    for (i, table) in tables.into_iter().enumerate() {
-        let table = if i == TABLE_DEPTH - 1 {
-            table.into_flat_bytes()
-        } else {
-            table.into_bytes()
-        };
+    for (i, table) in into_table_bytes(tables)?.into_iter().enumerate() {
         let table_name = format_ident!("TABLE_{i}");
         let table_len = table.len();
         quote! {
-            pub(crate) static #table_name: [u8; #table_len]  = [#(#table),*];
+            pub static #table_name: [u8; #table_len]  = [#(#table),*];
         }
         .to_tokens(&mut res)
     }
+    emit_lookup_width(split).to_tokens(&mut res);
 
     Ok(res)
 }
 
-fn emit_emoji_variations(set: TrieSetOwned) -> TokenStream {
+fn emit_lookup_width(split: &TableSplit) -> TokenStream {
+    let entries_per_byte = u8::BITS / BITS_PER_CODEPOINT as u32;
+    let packed_bits = entries_per_byte.ilog2();
+    let width_mask = Literal::u8_unsuffixed((1 << BITS_PER_CODEPOINT) - 1);
+    let codepoint_mask = Literal::usize_unsuffixed(entries_per_byte as usize - 1);
+    let bits_per_codepoint = Literal::usize_unsuffixed(BITS_PER_CODEPOINT as usize);
+
+    let mut body = TokenStream::new();
+    let mut offset = None;
+    for (i, &(low_bit, cap_bit)) in split.levels.iter().enumerate() {
+        let is_last = i == split.depth() - 1;
+        let table = format_ident!("TABLE_{i}");
+        // the last level packs multiple widths into each byte so the low bits
+        // select the width within the byte instead of the table entry
+        let (low_bit, num_bits) = if is_last {
+            (low_bit + packed_bits, cap_bit - low_bit - packed_bits)
+        } else {
+            (low_bit, cap_bit - low_bit)
+        };
+        let low_bit = Literal::u32_unsuffixed(low_bit);
+        let mask = Literal::usize_unsuffixed((1 << num_bits) - 1);
+        let sub_table_len = Literal::usize_unsuffixed(1 << num_bits);
+        let index = match offset {
+            None => quote!(cp >> #low_bit & #mask),
+            Some(offset) => {
+                quote!(#sub_table_len * usize::from(#offset) + (cp >> #low_bit & #mask))
+            }
+        };
+        let var = if is_last {
+            format_ident!("packed_widths")
+        } else {
+            format_ident!("offset_{i}")
+        };
+        quote!(let #var = #table[#index];).to_tokens(&mut body);
+        offset = Some(var);
+    }
+    quote! {
+        /// Looks up the width of a codepoint in the width tables.
+        ///
+        /// The bits of the codepoint are split into one index per table.
+        /// Each index selects an entry in the sub-table chosen by the previous level.
+        /// The last level packs multiple widths into each byte.
+        #[inline]
+        pub fn lookup_width(c: char) -> u8 {
+            let cp = c as usize;
+            #body
+            packed_widths >> (#bits_per_codepoint * (cp & #codepoint_mask)) & #width_mask
+        }
+    }
+}
+
+fn emit_trie_set(name: &str, cfg: TokenStream, set: TrieSetOwned) -> TokenStream {
     let TrieSetSlice {
         tree1_level1,
         tree2_level1,
@@ -387,8 +906,10 @@ fn emit_emoji_variations(set: TrieSetOwned) -> TokenStream {
         tree3_level2,
         tree3_level3,
     } = set.as_slice();
+    let name = format_ident!("{name}");
     quote! {
-        pub(crate) const EMOJI_VARIATIONS: &'static ::ucd_trie::TrieSet = &::ucd_trie::TrieSet {
+        #[cfg(#cfg)]
+        pub(crate) const #name: &'static ::ucd_trie::TrieSet = &::ucd_trie::TrieSet {
             tree1_level1: &[#(#tree1_level1),*],
             tree2_level1: &[#(#tree2_level1),*],
             tree2_level2: &[#(#tree2_level2),*],
@@ -400,24 +921,394 @@ fn emit_emoji_variations(set: TrieSetOwned) -> TokenStream {
     .to_token_stream()
 }
 
+fn emit_grapheme_break(classes: &BTreeMap<u32, &'static str>) -> TokenStream {
+    let mut ranges: Vec<(u32, u32, &str)> = Vec::new();
+    for (&codepoint, &class) in classes {
+        match ranges.last_mut() {
+            Some((_, end, last_class)) if *end + 1 == codepoint && *last_class == class => {
+                *end = codepoint
+            }
+            _ => ranges.push((codepoint, codepoint, class)),
+        }
+    }
+    let ranges = ranges.into_iter().map(|(start, end, class)| {
+        let start: Literal = format!("{start:#X}").parse().unwrap();
+        let end: Literal = format!("{end:#X}").parse().unwrap();
+        let class = format_ident!("{class}");
+        quote!((#start, #end, #class))
+    });
+    quote! {
+        use crate::uax29::GraphemeBreak::{self, *};
+
+        /// Sorted, non-overlapping ranges of all codepoints with a grapheme cluster break class other
+        /// than `Other` (except the `LV` and `LVT` hangul syllables)
+        #[cfg(not(feature = "ascii-only"))]
+        pub(crate) const GRAPHEME_BREAK: &[(u32, u32, GraphemeBreak)] = &[#(#ranges),*];
+    }
+}
+
+fn emit_emoji_zwj_sequences(sequences: &[String]) -> TokenStream {
+    quote! {
+        /// All RGI emoji ZWJ sequences (without VS16) sorted by codepoints
+        #[cfg(all(feature = "emoji", not(feature = "ascii-only")))]
+        pub(crate) const EMOJI_ZWJ_SEQUENCES: &[&str] = &[#(#sequences),*];
+    }
+}
+
 impl GenTables {
     pub fn run(self, sh: &Shell) -> Result<()> {
         let version = self.unicode_version;
+        let split = match &self.split {
+            Some(split) => split.parse()?,
+            None => TableSplit::default(),
+        };
         println!("generating tables for Unicode {version}");
-        let raw_data = RawUnicodeData::new(&version)?;
-        let code_point_data = raw_data.codepoint_data()?;
-        let width_tables = code_point_data.compress_widths();
+        let source = match self.ucd_zip {
+            Some(archive) => UcdSource::Zip(archive),
+            None => UcdSource::Download(version.clone()),
+        };
+        let source_description = source.describe()?;
+        let mut raw_data = RawUnicodeData::new(&source)?;
+        if self.age_accurate {
+            raw_data.limit_age(&source, &version)?;
+        }
+        let mut code_point_data = raw_data.codepoint_data()?;
+        if let Some(overrides) = &self.overrides {
+            println!("applying width overrides from {}...", overrides.display());
+            let overrides = sh.read_file(overrides)?;
+            code_point_data.apply_width_overrides(&overrides)?;
+        }
+        let width_tables = code_point_data.compress_widths(&split);
         let emoji_variations = code_point_data.compress_emoji_variations();
+        let emoji_presentation = code_point_data.compress_emoji_presentation();
+        let ambiguous = code_point_data.compress_ambiguous();
+        let grapheme_break = emit_grapheme_break(&code_point_data.grapheme_break);
+        let unassigned = code_point_data.compress_unassigned();
         println!("generating table.rs...");
-        let table = emit_width_table(width_tables, &version)?;
+        let table = emit_width_table(width_tables, &split, &version)?;
         let table = reformat(sh, table.to_string());
-        let table = format!("//! Generated by `cargo xtask gen-tables`, do not edit by hand.\n//! This file contains a three level LUT for determining the display width of a unicode grapheme.\n//! It was generated from UCD {version}\n\n{table}");
-        sh.write_file("src/table.rs", table)?;
+        let depth = split.depth();
+        let table = format!("//! Generated by `cargo xtask gen-tables`, do not edit by hand.\n//! This file contains a {depth} level LUT for determining the display width of a unicode grapheme.\n//! It was generated from UCD {version}\n//! Source: {source_description}\n\n{table}");
+        sh.write_file("tables/src/table.rs", table)?;
         println!("generating emoji_variations.rs...");
-        let emoji_variations = emit_emoji_variations(emoji_variations);
+        let emoji_variations = emit_trie_set(
+            "EMOJI_VARIATIONS",
+            quote!(feature = "emoji"),
+            emoji_variations,
+        );
         let emoji_variations = reformat(sh, emoji_variations.to_string());
-        let emoji_variations = format!("//! Generated by `cargo xtask gen-tables`, do not edit by hand.\n//! This file contains a UCD tri-set for determining whether an emojis presentation can be controlled with VS15/VS16.\n//! It was generated from UCD {version}\n\n{emoji_variations}");
+        let emoji_variations = format!("//! Generated by `cargo xtask gen-tables`, do not edit by hand.\n//! This file contains a UCD tri-set for determining whether an emojis presentation can be controlled with VS15/VS16.\n//! It was generated from UCD {version}\n//! Source: {source_description}\n\n{emoji_variations}");
         sh.write_file("src/emoji_variations.rs", emoji_variations)?;
+        println!("generating emoji_presentation.rs...");
+        let emoji_presentation = emit_trie_set(
+            "EMOJI_PRESENTATION",
+            quote!(feature = "emoji"),
+            emoji_presentation,
+        );
+        let emoji_presentation = reformat(sh, emoji_presentation.to_string());
+        let emoji_presentation = format!("//! Generated by `cargo xtask gen-tables`, do not edit by hand.\n//! This file contains a UCD tri-set for determining whether an emoji has emoji presentation by default.\n//! It was generated from UCD {version}\n//! Source: {source_description}\n\n{emoji_presentation}");
+        sh.write_file("src/emoji_presentation.rs", emoji_presentation)?;
+        println!("generating emoji_zwj_sequences.rs...");
+        let emoji_zwj_sequences = emit_emoji_zwj_sequences(&code_point_data.emoji_zwj_sequences);
+        let emoji_zwj_sequences = reformat(sh, emoji_zwj_sequences.to_string());
+        let emoji_zwj_sequences = format!("//! Generated by `cargo xtask gen-tables`, do not edit by hand.\n//! This file contains a sorted list of the emoji ZWJ sequences recommended for general interchange (RGI).\n//! It was generated from UCD {version}\n//! Source: {source_description}\n\n{emoji_zwj_sequences}");
+        sh.write_file("src/emoji_zwj_sequences.rs", emoji_zwj_sequences)?;
+        println!("generating ambiguous.rs...");
+        let ambiguous = emit_trie_set(
+            "AMBIGUOUS_WIDTH",
+            quote!(not(feature = "ascii-only")),
+            ambiguous,
+        );
+        let ambiguous = reformat(sh, ambiguous.to_string());
+        let ambiguous = format!("//! Generated by `cargo xtask gen-tables`, do not edit by hand.\n//! This file contains a UCD tri-set for determining whether a character has ambiguous east asian width.\n//! It was generated from UCD {version}\n//! Source: {source_description}\n\n{ambiguous}");
+        sh.write_file("src/ambiguous.rs", ambiguous)?;
+        println!("generating unassigned.rs...");
+        let unassigned = emit_trie_set(
+            "UNASSIGNED",
+            quote!(not(feature = "ascii-only")),
+            unassigned,
+        );
+        let unassigned = reformat(sh, unassigned.to_string());
+        let unassigned = format!("//! Generated by `cargo xtask gen-tables`, do not edit by hand.\n//! This file contains a UCD tri-set for determining whether a codepoint is unassigned (general category Cn).\n//! It was generated from UCD {version}\n//! Source: {source_description}\n\n{unassigned}");
+        sh.write_file("src/unassigned.rs", unassigned)?;
+        println!("generating grapheme_break.rs...");
+        let grapheme_break = reformat(sh, grapheme_break.to_string());
+        let grapheme_break = format!("//! Generated by `cargo xtask gen-tables`, do not edit by hand.\n//! This file contains a sorted range table for determining the grapheme cluster break class of a character.\n//! It was generated from UCD {version}\n//! Source: {source_description}\n\n{grapheme_break}");
+        sh.write_file("src/grapheme_break.rs", grapheme_break)?;
         Ok(())
     }
 }
+
+impl DumpWidths {
+    pub fn run(self) -> Result<()> {
+        let source = match self.ucd_zip {
+            Some(archive) => UcdSource::Zip(archive),
+            None => UcdSource::Download(self.unicode_version),
+        };
+        let raw_data = RawUnicodeData::new(&source)?;
+        let ranges = raw_data.codepoint_data()?.width_ranges();
+        if self.json {
+            print!("{}", emit_width_ranges_json(&ranges));
+        } else {
+            print!("{}", emit_width_ranges(&ranges));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Synthetic width data that roughly resembles the real tables
+    fn synthetic_widths() -> CodePointData {
+        let mut widths: Box<UncompressedTable> =
+            vec![1; NUM_CODEPOINTS as usize].try_into().unwrap();
+        let wide = [
+            0x1100..=0x115F,
+            0x2E80..=0xA4CF,
+            0xAC00..=0xD7A3,
+            0x1F300..=0x1F64F,
+            0x20000..=0x3FFFD,
+        ];
+        let zero = [
+            0x0..=0x1F,
+            0x7F..=0x9F,
+            0x300..=0x36F,
+            0x200B..=0x200F,
+            0xD800..=0xDFFF,
+            0xE0000..=0xE0FFF,
+        ];
+        for range in wide {
+            widths[range].fill(2);
+        }
+        for range in zero {
+            widths[range].fill(0);
+        }
+        for codepoint in (0x10000..0x12000).step_by(97) {
+            widths[codepoint] = 0;
+        }
+        CodePointData {
+            widths,
+            ambiguous: HashSet::new(),
+            grapheme_break: BTreeMap::new(),
+            unassigned: HashSet::new(),
+            emoji_variations: HashSet::new(),
+            emoji_presentation: HashSet::new(),
+            emoji_zwj_sequences: Vec::new(),
+        }
+    }
+
+    /// Interprets the emitted tables the same way the generated `lookup_width` does
+    fn lookup(tables: &[Vec<u8>], split: &TableSplit, codepoint: usize) -> u8 {
+        let mut offset = 0;
+        for (i, &(low_bit, cap_bit)) in split.levels.iter().enumerate() {
+            if i == split.depth() - 1 {
+                let num_bits = cap_bit - low_bit - 2;
+                let index = (offset << num_bits) + (codepoint >> 2 & ((1 << num_bits) - 1));
+                return tables[i][index] >> (2 * (codepoint & 0b11)) & 0b11;
+            }
+            let num_bits = cap_bit - low_bit;
+            let index = (offset << num_bits) + (codepoint >> low_bit & ((1 << num_bits) - 1));
+            offset = usize::from(tables[i][index]);
+        }
+        unreachable!()
+    }
+
+    #[test]
+    fn parse_table_split() {
+        assert_eq!(
+            "13,6,0".parse::<TableSplit>().unwrap(),
+            TableSplit::default()
+        );
+        assert_eq!(
+            "12, 7, 3, 0".parse::<TableSplit>().unwrap().levels,
+            [(12, 21), (7, 12), (3, 7), (0, 3)]
+        );
+        assert!("6,13,0".parse::<TableSplit>().is_err());
+        assert!("21,6,0".parse::<TableSplit>().is_err());
+        assert!("13,6".parse::<TableSplit>().is_err());
+        assert!("13,1,0".parse::<TableSplit>().is_err());
+        assert!("13,a,0".parse::<TableSplit>().is_err());
+    }
+
+    #[test]
+    fn width_overrides() {
+        let mut data = synthetic_widths();
+        assert_eq!(data.widths[0x41], 1);
+        assert_eq!(data.widths[0x4E00], 2);
+        data.apply_width_overrides(include_str!("../fixtures/overrides.txt"))
+            .unwrap();
+        let split = TableSplit::default();
+        let tables = into_table_bytes(data.compress_widths(&split)).unwrap();
+        assert_eq!(lookup(&tables, &split, 0x41), 0);
+        assert_eq!(lookup(&tables, &split, 0x42), 1);
+        for codepoint in 0x4E00..=0x4E0F {
+            assert_eq!(lookup(&tables, &split, codepoint), 1);
+        }
+        assert_eq!(lookup(&tables, &split, 0x4E10), 2);
+
+        assert!(data.apply_width_overrides("0041;3").is_err());
+        assert!(data.apply_width_overrides("0041").is_err());
+        assert!(data.apply_width_overrides("110000;1").is_err());
+    }
+
+    #[test]
+    fn ucd_zip() {
+        let archive = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("fixtures/UCD.zip");
+        let raw_data = RawUnicodeData::new(&UcdSource::Zip(archive.clone())).unwrap();
+        let data = raw_data.codepoint_data().unwrap();
+        assert_eq!(data.widths[0x41], 1);
+        assert_eq!(data.widths[0x300], 0);
+        assert_eq!(data.widths[0x3000], 2);
+        assert_eq!(data.widths[0x1F600], 2);
+        assert_eq!(data.ambiguous, (0x300..=0x36F).collect());
+        // spacing marks keep their width, other Mc marks are zero width
+        assert_eq!(data.widths[0xBBE], 0);
+        assert_eq!(data.widths[0xBBF], 1);
+        assert_eq!(raw_data.spacing_marks().unwrap(), HashSet::from([0xBBF]));
+        assert_eq!(data.grapheme_break[&0x300], "Extend");
+        assert_eq!(data.grapheme_break[&0xBBF], "SpacingMark");
+        assert_eq!(data.grapheme_break[&0x1100], "L");
+        assert_eq!(data.grapheme_break[&0x200D], "Zwj");
+        assert_eq!(data.grapheme_break[&0x1F600], "ExtendedPictographic");
+        assert!(!data.grapheme_break.contains_key(&0x41));
+        // hangul syllables are not part of the table
+        assert!(!data.grapheme_break.contains_key(&0xAC00));
+        assert!(!data.grapheme_break.contains_key(&0xAC01));
+        assert!(!data.unassigned.contains(&0x41));
+        assert!(data.unassigned.contains(&0x42));
+        assert!(!data.unassigned.contains(&0x4E00));
+        assert!(!data.unassigned.contains(&0x4E01));
+        assert!(!data.unassigned.contains(&0x9FFF));
+        assert!(data.unassigned.contains(&0xA000));
+        assert_eq!(data.emoji_presentation, HashSet::from([0x1F600, 0x1FAE8]));
+        assert_eq!(data.emoji_variations, HashSet::from([0x2714]));
+        assert_eq!(
+            data.emoji_zwj_sequences,
+            [
+                "\u{1F3F3}\u{200D}\u{1F308}",
+                "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}"
+            ]
+        );
+
+        let missing = UcdSource::Zip(archive).retrieve_file("Scripts");
+        assert!(missing.is_err());
+    }
+
+    #[test]
+    fn age_accurate() {
+        let archive = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("fixtures/UCD.zip");
+        let source = UcdSource::Zip(archive);
+        let mut raw_data = RawUnicodeData::new(&source).unwrap();
+        // SHAKING FACE (U+1FAE8) was added in unicode 15.0
+        let data = raw_data.codepoint_data().unwrap();
+        assert_eq!(data.widths[0x1FAE8], 2);
+        assert!(data.emoji_presentation.contains(&0x1FAE8));
+        assert!(!data.unassigned.contains(&0x1FAE8));
+
+        raw_data.limit_age(&source, "9.0.0").unwrap();
+        let data = raw_data.codepoint_data().unwrap();
+        assert_eq!(data.widths[0x1FAE8], 1);
+        assert!(!data.emoji_presentation.contains(&0x1FAE8));
+        assert!(data.unassigned.contains(&0x1FAE8));
+        assert_eq!(data.grapheme_break[&0x1FAE8], "ExtendedPictographic");
+        // codepoints assigned before 9.0 are not affected
+        assert_eq!(data.widths[0x1F600], 2);
+        assert!(data.emoji_presentation.contains(&0x1F600));
+        assert!(!data.unassigned.contains(&0x9FA6));
+
+        raw_data.limit_age(&source, "15.0.0").unwrap();
+        let data = raw_data.codepoint_data().unwrap();
+        assert_eq!(data.widths[0x1FAE8], 2);
+        assert!(raw_data.limit_age(&source, "15").is_err());
+    }
+
+    /// Minimal parser for the JSON emitted by `emit_width_ranges_json`
+    fn parse_width_ranges_json(json: &str) -> Vec<WidthRange> {
+        let json = json
+            .trim()
+            .strip_prefix('[')
+            .unwrap()
+            .strip_suffix(']')
+            .unwrap();
+        json.split('}')
+            .map(|object| object.trim().trim_start_matches(',').trim())
+            .filter(|object| !object.is_empty())
+            .map(|object| {
+                let object = object.strip_prefix('{').unwrap();
+                let mut fields = object.split(',').map(|field| {
+                    let (key, value) = field.split_once(':').unwrap();
+                    (key.trim(), value.trim().parse::<u32>().unwrap())
+                });
+                let (
+                    Some(("\"start\"", start)),
+                    Some(("\"end\"", end)),
+                    Some(("\"width\"", width)),
+                    None,
+                ) = (fields.next(), fields.next(), fields.next(), fields.next())
+                else {
+                    panic!("invalid range {object}")
+                };
+                WidthRange {
+                    start,
+                    end,
+                    width: width as u8,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn dump_widths() {
+        let data = synthetic_widths();
+        let ranges = data.width_ranges();
+        assert_eq!(
+            ranges[0],
+            WidthRange {
+                start: 0,
+                end: 0x1F,
+                width: 0
+            }
+        );
+        assert_eq!(
+            ranges[1],
+            WidthRange {
+                start: 0x20,
+                end: 0x7E,
+                width: 1
+            }
+        );
+        assert_eq!(ranges.last().unwrap().end, NUM_CODEPOINTS - 1);
+        for window in ranges.windows(2) {
+            assert_eq!(window[0].end + 1, window[1].start);
+            assert_ne!(window[0].width, window[1].width);
+        }
+
+        let json = emit_width_ranges_json(&ranges);
+        assert!(json.starts_with("[\n  {\"start\":0,\"end\":31,\"width\":0},\n"));
+        assert_eq!(parse_width_ranges_json(&json), ranges);
+
+        let mut roundtrip = synthetic_widths();
+        roundtrip.widths.fill(u8::MAX);
+        roundtrip
+            .apply_width_overrides(&emit_width_ranges(&ranges))
+            .unwrap();
+        assert_eq!(roundtrip.width_ranges(), ranges);
+    }
+
+    #[test]
+    fn alternate_split_lookup() {
+        let data = synthetic_widths();
+        let default_split = TableSplit::default();
+        let default_tables = into_table_bytes(data.compress_widths(&default_split)).unwrap();
+        let split: TableSplit = "12,7,3,0".parse().unwrap();
+        let tables = into_table_bytes(data.compress_widths(&split)).unwrap();
+        assert_eq!(tables.len(), 4);
+        for codepoint in (0..NUM_CODEPOINTS as usize).step_by(7).chain([0x10FFFF]) {
+            let width = data.widths[codepoint];
+            assert_eq!(lookup(&default_tables, &default_split, codepoint), width);
+            assert_eq!(lookup(&tables, &split, codepoint), width, "{codepoint:x}");
+        }
+    }
+}
@@ -0,0 +1,221 @@
+//! Compares the widths computed by `grapheme_width` to the widths of another
+//! implementation to catch bugs in the table generation. By default the widths
+//! are compared to `UnicodeWidthChar::width` of the `unicode-width` crate
+//! (which is pinned to a release with the same Unicode version). Alternatively
+//! the reference widths are read from a file with one `codepoints;width` line
+//! per range.
+
+use std::ops::RangeInclusive;
+
+use anyhow::{bail, Result};
+use grapheme_width::{char_width_unicode9, grapheme_indices, UNICODE_VERSION};
+use unicode_width::UnicodeWidthChar;
+use xshell::Shell;
+
+use crate::flags::Validate;
+use crate::tables::{parse_codepoints, parse_data_line, NUM_CODEPOINTS};
+
+/// Codepoints where `grapheme_width` intentionally differs from other
+/// implementations like the `unicode-width` crate. Divergences
+/// in these ranges are not reported.
+const EXPECTED_DIFFERENCES: &[(RangeInclusive<u32>, &str)] = &[
+    // hardcoded overrides in `fill_hardcoded_widths`
+    (0x00AD..=0x00AD, "soft hyphen is always single width"),
+    (
+        0x1160..=0x11FF,
+        "hangul jamo medial vowels & final consonants are zero width",
+    ),
+    (0x2028..=0x2029, "line and paragraph separators are zero width"),
+    (0xFDD0..=0xFDEF, "noncharacters are zero width"),
+    (0xFFFE..=0xFFFF, "noncharacters are zero width"),
+    (0x1FFFE..=0x1FFFF, "noncharacters are zero width"),
+    (0x2FFFE..=0x2FFFF, "noncharacters are zero width"),
+    (0x3FFFE..=0x3FFFF, "noncharacters are zero width"),
+    (0x4FFFE..=0x4FFFF, "noncharacters are zero width"),
+    (0x5FFFE..=0x5FFFF, "noncharacters are zero width"),
+    (0x6FFFE..=0x6FFFF, "noncharacters are zero width"),
+    (0x7FFFE..=0x7FFFF, "noncharacters are zero width"),
+    (0x8FFFE..=0x8FFFF, "noncharacters are zero width"),
+    (0x9FFFE..=0x9FFFF, "noncharacters are zero width"),
+    (0xAFFFE..=0xAFFFF, "noncharacters are zero width"),
+    (0xBFFFE..=0xBFFFF, "noncharacters are zero width"),
+    (0xCFFFE..=0xCFFFF, "noncharacters are zero width"),
+    (0xDFFFE..=0xDFFFF, "noncharacters are zero width"),
+    (0xEFFFE..=0xEFFFF, "noncharacters are zero width"),
+    (0xFFFFE..=0xFFFFF, "noncharacters are zero width"),
+    (0x10FFFE..=0x10FFFF, "noncharacters are zero width"),
+    // spacing combining marks (Mc) that are neither `Grapheme_Cluster_Break=SpacingMark`
+    // nor `Extend` (the latter are covered by `extends_grapheme`)
+    (0x102B..=0x102C, "Mc marks that are not spacing marks are zero width"),
+    (0x1038..=0x1038, "Mc marks that are not spacing marks are zero width"),
+    (0x1062..=0x1064, "Mc marks that are not spacing marks are zero width"),
+    (0x1067..=0x106D, "Mc marks that are not spacing marks are zero width"),
+    (0x1083..=0x1083, "Mc marks that are not spacing marks are zero width"),
+    (0x1087..=0x108C, "Mc marks that are not spacing marks are zero width"),
+    (0x108F..=0x108F, "Mc marks that are not spacing marks are zero width"),
+    (0x109A..=0x109C, "Mc marks that are not spacing marks are zero width"),
+    (0x1A61..=0x1A61, "Mc marks that are not spacing marks are zero width"),
+    (0x1A63..=0x1A64, "Mc marks that are not spacing marks are zero width"),
+    (0xAA7B..=0xAA7B, "Mc marks that are not spacing marks are zero width"),
+    (0xAA7D..=0xAA7D, "Mc marks that are not spacing marks are zero width"),
+    (0x11720..=0x11721, "Mc marks that are not spacing marks are zero width"),
+    // emoji that are usually part of a sequence are still wide on their own
+    (
+        0x1F1E6..=0x1F1FF,
+        "regional indicators always use emoji presentation",
+    ),
+    (
+        0x1F3FB..=0x1F3FF,
+        "emoji modifiers always use emoji presentation",
+    ),
+];
+
+/// Returns whether `c` is part of the grapheme of a preceding character.
+/// `grapheme_width` treats spacing combining marks (Mc) as zero width unless they
+/// are `Grapheme_Cluster_Break=SpacingMark`, while other implementations like
+/// `unicode-width` give all of them a width of one.
+fn extends_grapheme(c: char) -> bool {
+    grapheme_indices(&format!("a{c}")).count() == 1
+}
+
+/// A range of consecutive codepoints where the widths diverge in the same way.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Divergence {
+    codepoints: RangeInclusive<u32>,
+    width: u8,
+    reference_width: u8,
+}
+
+/// Parses the reference widths from the `codepoints;width` lines in `reference`.
+/// Codepoints that are missing from `reference` are `None` and are not compared.
+fn parse_reference_widths(reference: &str) -> Result<Vec<Option<u8>>> {
+    let mut reference_widths = vec![None; NUM_CODEPOINTS as usize];
+    for line in reference.lines() {
+        let Some(fields) = parse_data_line(line) else { continue };
+        let [codepoints, width] = fields.as_slice() else {
+            bail!("invalid reference width {line:?}, expected `codepoint;width`")
+        };
+        let codepoints = parse_codepoints(codepoints)?;
+        let Some(widths) = reference_widths.get_mut(codepoints) else {
+            bail!("reference width {line:?} is outside the unicode codespace")
+        };
+        let width = width.parse()?;
+        widths.fill(Some(width));
+    }
+    Ok(reference_widths)
+}
+
+/// The width of every codepoint according to the `unicode-width` crate.
+/// Control characters (which `unicode-width` does not assign a width) are zero width.
+fn unicode_width_widths() -> Vec<Option<u8>> {
+    (0..NUM_CODEPOINTS)
+        .map(|codepoint| {
+            let c = char::from_u32(codepoint)?;
+            Some(UnicodeWidthChar::width(c).unwrap_or(0) as u8)
+        })
+        .collect()
+}
+
+/// Compares the widths of `grapheme_width` to `reference_widths`.
+fn divergences(reference_widths: Vec<Option<u8>>) -> Vec<Divergence> {
+    let mut divergences: Vec<Divergence> = Vec::new();
+    for (codepoint, reference_width) in (0..NUM_CODEPOINTS).zip(reference_widths) {
+        let (Some(c), Some(reference_width)) = (char::from_u32(codepoint), reference_width) else {
+            continue;
+        };
+        let width = char_width_unicode9(c) as u8;
+        let expected = EXPECTED_DIFFERENCES
+            .iter()
+            .any(|(codepoints, _)| codepoints.contains(&codepoint))
+            || (width == 0 && extends_grapheme(c));
+        if width == reference_width || expected {
+            continue;
+        }
+        match divergences.last_mut() {
+            Some(divergence)
+                if *divergence.codepoints.end() + 1 == codepoint
+                    && divergence.width == width
+                    && divergence.reference_width == reference_width =>
+            {
+                divergence.codepoints = *divergence.codepoints.start()..=codepoint;
+            }
+            _ => divergences.push(Divergence {
+                codepoints: codepoint..=codepoint,
+                width,
+                reference_width,
+            }),
+        }
+    }
+    divergences
+}
+
+impl Validate {
+    pub fn run(self, sh: &Shell) -> Result<()> {
+        let reference_widths = match &self.reference {
+            Some(reference) => parse_reference_widths(&sh.read_file(reference)?)?,
+            None => {
+                if unicode_width::UNICODE_VERSION != UNICODE_VERSION {
+                    bail!(
+                        "unicode-width uses unicode {:?} but the tables were generated from {:?}",
+                        unicode_width::UNICODE_VERSION,
+                        UNICODE_VERSION
+                    );
+                }
+                unicode_width_widths()
+            }
+        };
+        let divergences = divergences(reference_widths);
+        for divergence in &divergences {
+            let (start, end) = divergence.codepoints.clone().into_inner();
+            println!(
+                "{start:04X}..{end:04X}: grapheme_width {}, reference {}",
+                divergence.width, divergence.reference_width
+            );
+        }
+        if !divergences.is_empty() {
+            bail!("found {} divergent ranges", divergences.len());
+        }
+        println!("no unexpected divergences");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_divergences() {
+        let reference = "0041..0042;1\n0301;0\n4E00;2\nFDD0;1\n";
+        assert_eq!(divergences(parse_reference_widths(reference).unwrap()), []);
+        let reference = "0041;1\n0061..0063;2\n0064;0\n4E00..4E01;1\n";
+        assert_eq!(
+            divergences(parse_reference_widths(reference).unwrap()),
+            [
+                Divergence {
+                    codepoints: 0x61..=0x63,
+                    width: 1,
+                    reference_width: 2,
+                },
+                Divergence {
+                    codepoints: 0x64..=0x64,
+                    width: 1,
+                    reference_width: 0,
+                },
+                Divergence {
+                    codepoints: 0x4E00..=0x4E01,
+                    width: 2,
+                    reference_width: 1,
+                },
+            ]
+        );
+        assert!(parse_reference_widths("0041").is_err());
+        assert!(parse_reference_widths("110000;1").is_err());
+    }
+
+    #[test]
+    fn matches_unicode_width() {
+        assert_eq!(unicode_width::UNICODE_VERSION, UNICODE_VERSION);
+        assert_eq!(divergences(unicode_width_widths()), []);
+    }
+}